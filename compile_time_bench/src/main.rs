@@ -0,0 +1,252 @@
+//! Compile-time benchmark for the type-level encoding of string literals.
+//!
+//! For every requested literal length, this generates two small crates
+//! which each evaluate the same amount of `&'static str` constants:
+//!
+//! - `list` uses the former encoding: a type-level list with one `Byte<VAL>` per byte.
+//! - `chunks` uses the current encoding of [`overloaded_literals::type_bytes`]:
+//!   a balanced tree of `Concat`s over `Chunk`s, which pack 16 bytes in a single `u128`.
+//!
+//! Both crates are built once to compile their dependencies,
+//! after which the crate itself is rebuilt from scratch and timed.
+//!
+//! Usage: `cargo run -p compile_time_bench --release -- [--literals=N] [LENGTH...]`
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use std::{env, fs};
+
+use overloaded_literals::type_bytes::CHUNK_SIZE;
+
+const DEFAULT_LITERALS: usize = 50;
+const DEFAULT_LENGTHS: &[usize] = &[16, 64, 128, 256, 512];
+#[derive(Clone, Copy)]
+enum Encoding {
+    List,
+    Chunks,
+}
+
+impl Encoding {
+    fn name(self) -> &'static str {
+        match self {
+            Encoding::List => "list",
+            Encoding::Chunks => "chunks",
+        }
+    }
+}
+
+fn main() {
+    let mut literals = DEFAULT_LITERALS;
+    let mut lengths = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.strip_prefix("--literals=") {
+            Some(count) => literals = count.parse().expect("--literals expects a number"),
+            None => lengths.push(arg.parse().expect("lengths should be numbers")),
+        }
+    }
+    if lengths.is_empty() {
+        lengths = DEFAULT_LENGTHS.to_vec();
+    }
+
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let library = manifest_dir
+        .parent()
+        .unwrap()
+        .join("overloaded_literals")
+        .canonicalize()
+        .expect("the overloaded_literals crate should be next to the benchmark");
+    let work_dir = manifest_dir
+        .parent()
+        .unwrap()
+        .join("target")
+        .join("compile_time_bench");
+
+    println!("Building {literals} distinct string literals per crate.\n");
+    println!("| bytes per literal | `Byte<VAL>` list | `u128` chunks |");
+    println!("|------------------:|-----------------:|--------------:|");
+    for length in lengths {
+        let list = bench(&work_dir, &library, Encoding::List, literals, length);
+        let chunks = bench(&work_dir, &library, Encoding::Chunks, literals, length);
+        println!(
+            "| {length} | {} | {} |",
+            format_result(list),
+            format_result(chunks)
+        );
+    }
+}
+
+fn format_result(result: Option<Duration>) -> String {
+    match result {
+        Some(duration) => format!("{:.2}s", duration.as_secs_f64()),
+        None => "failed".to_string(),
+    }
+}
+
+/// Generates the crate for the given encoding and returns how long it took to build,
+/// or `None` if it did not compile (for instance because rustc overflowed its stack).
+fn bench(
+    work_dir: &Path,
+    library: &Path,
+    encoding: Encoding,
+    literals: usize,
+    length: usize,
+) -> Option<Duration> {
+    let name = format!("{}_{}", encoding.name(), length);
+    let crate_dir = work_dir.join(&name);
+    fs::create_dir_all(crate_dir.join("src")).unwrap();
+    fs::write(
+        crate_dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"{name}\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+             [dependencies]\noverloaded_literals = {{ path = {:?} }}\n\n[workspace]\n",
+            library.display().to_string()
+        ),
+    )
+    .unwrap();
+    let main_rs = crate_dir.join("src").join("main.rs");
+
+    // Build the dependencies (and an empty main) so they are not part of the measurement.
+    fs::write(&main_rs, "fn main() {}\n").unwrap();
+    if !cargo_build(&crate_dir, work_dir) {
+        return None;
+    }
+
+    fs::write(&main_rs, generate(encoding, literals, length)).unwrap();
+    let start = Instant::now();
+    cargo_build(&crate_dir, work_dir).then(|| start.elapsed())
+}
+
+fn cargo_build(crate_dir: &Path, work_dir: &Path) -> bool {
+    Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .arg("build")
+        .arg("--quiet")
+        .current_dir(crate_dir)
+        .env("CARGO_TARGET_DIR", work_dir.join("target"))
+        .env("CARGO_INCREMENTAL", "0")
+        // Deeply nested types need a lot of stack in rustc. Give both encodings the same amount.
+        .env("RUST_MIN_STACK", (256 * 1024 * 1024).to_string())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// The bytes of the `index`th literal. Every literal is different, so no constant can be reused.
+fn literal(index: usize, length: usize) -> Vec<u8> {
+    (0..length)
+        .map(|pos| b'a' + ((index * 7 + pos) % 26) as u8)
+        .collect()
+}
+
+fn generate(encoding: Encoding, literals: usize, length: usize) -> String {
+    let mut out = String::new();
+    match encoding {
+        Encoding::List => out.push_str(LIST_PRELUDE),
+        Encoding::Chunks => out.push_str(CHUNKS_PRELUDE),
+    }
+    writeln!(out, "fn main() {{").unwrap();
+    writeln!(out, "    let strs: [&str; {literals}] = [").unwrap();
+    for index in 0..literals {
+        let bytes = literal(index, length);
+        let ty = match encoding {
+            Encoding::List => list_type(&bytes),
+            Encoding::Chunks => chunks_type(&bytes),
+        };
+        writeln!(out, "        <{ty} as TypeStr>::STR,").unwrap();
+    }
+    writeln!(out, "    ];").unwrap();
+    writeln!(
+        out,
+        "    println!(\"{{}}\", strs.iter().map(|s| s.len()).sum::<usize>());"
+    )
+    .unwrap();
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+/// Builds the type in the same way as the `overloaded_literals` macro does.
+fn chunks_type(bytes: &[u8]) -> String {
+    let len = bytes.len();
+    if len <= CHUNK_SIZE {
+        let val = bytes
+            .iter()
+            .rev()
+            .fold(0u128, |acc, &byte| (acc << 8) | u128::from(byte));
+        return format!("Chunk<{len}, {val}>");
+    }
+    let (left, right) = bytes.split_at(len.div_ceil(CHUNK_SIZE) / 2 * CHUNK_SIZE);
+    format!(
+        "Concat<{len}, {}, {}>",
+        chunks_type(left),
+        chunks_type(right)
+    )
+}
+
+/// Builds the type in the same way as the `overloaded_literals` macro did before it packed bytes into chunks.
+fn list_type(bytes: &[u8]) -> String {
+    let mut list = "TNil".to_string();
+    for byte in bytes.iter().rev() {
+        list = format!("TCons<Byte<{byte}>, {list}>");
+    }
+    format!("Bytes<{}, {list}>", bytes.len())
+}
+
+const CHUNKS_PRELUDE: &str = "\
+use overloaded_literals::type_bytes::{Chunk, Concat};
+use overloaded_literals::TypeStr;
+
+";
+
+/// A copy of the former `Byte<VAL>` list encoding.
+const LIST_PRELUDE: &str = "\
+#![recursion_limit = \"4096\"]
+use core::marker::PhantomData;
+
+pub struct TNil;
+pub struct TCons<First, Rest>(PhantomData<(First, Rest)>);
+
+pub struct Byte<const VAL: u8>;
+
+pub trait ContainsByte {
+    const BYTE: u8;
+}
+
+impl<const BYTE: u8> ContainsByte for Byte<BYTE> {
+    const BYTE: u8 = BYTE;
+}
+
+pub trait ByteList<const LEN: usize> {
+    const COUNT: usize;
+    const ARR: [u8; LEN];
+}
+
+impl<const LEN: usize> ByteList<LEN> for TNil {
+    const COUNT: usize = 0;
+    const ARR: [u8; LEN] = [0; LEN];
+}
+
+impl<const LEN: usize, First: ContainsByte, Rest: ByteList<LEN>> ByteList<LEN> for TCons<First, Rest> {
+    const COUNT: usize = Rest::COUNT + 1;
+    const ARR: [u8; LEN] = {
+        let mut arr = Rest::ARR;
+        arr[LEN - Self::COUNT] = First::BYTE;
+        arr
+    };
+}
+
+pub struct Bytes<const LEN: usize, List>(PhantomData<List>);
+
+pub trait TypeStr {
+    const STR: &'static str;
+}
+
+impl<const LEN: usize, List: ByteList<LEN>> TypeStr for Bytes<LEN, List> {
+    const STR: &'static str = {
+        assert!(List::COUNT == LEN);
+        unsafe { core::str::from_utf8_unchecked(&List::ARR) }
+    };
+}
+
+";
@@ -0,0 +1,135 @@
+//! Helpers to build formatted messages at compile-time.
+//!
+//! `format!` cannot be used in const contexts, which makes it hard to write a helpful error message
+//! when a literal is rejected inside a `VALID_LITERAL` definition.
+//! [ConstMessage] fills this gap: it writes strings and integers into a fixed-size buffer,
+//! which can then be used to [panic](ConstMessage::panic).
+//!
+//! The built-in implementations use this for messages like `literal 300 is out of range for u8 (0..=255)`.
+
+/// Size of the buffer of a [ConstMessage].
+///
+/// Anything written beyond this length is cut off.
+pub const MAX_MESSAGE_LEN: usize = 256;
+
+/// A message built at compile-time, for use in the panics of `VALID_LITERAL` definitions.
+///
+/// All methods take and return the message by value, so they can be chained inside const contexts.
+///
+/// # Example
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralUnsigned, const_fmt::ConstMessage};
+///
+/// pub struct Percentage(u8);
+///
+/// impl<const LIT: u128> FromLiteralUnsigned<LIT> for Percentage {
+///     const VALID_LITERAL: u128 = {
+///         if LIT > 100 {
+///             ConstMessage::new()
+///                 .str("literal ")
+///                 .unsigned(LIT)
+///                 .str(" is not a valid Percentage (0..=100)")
+///                 .panic()
+///         }
+///         LIT
+///     };
+///
+///     fn into_self() -> Self {
+///         Percentage(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u8)
+///     }
+/// }
+///
+/// const MESSAGE: ConstMessage = ConstMessage::new().str("between ").signed(-5).str(" and ").unsigned(5);
+/// assert_eq!(MESSAGE.as_str(), "between -5 and 5");
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let full: Percentage = 100;
+///     // let boom: Percentage = 101; // <- compile error 'literal 101 is not a valid Percentage (0..=100)'
+/// }
+/// example()
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ConstMessage {
+    buf: [u8; MAX_MESSAGE_LEN],
+    len: usize,
+}
+
+impl ConstMessage {
+    /// Creates a new, empty message.
+    pub const fn new() -> Self {
+        ConstMessage {
+            buf: [0; MAX_MESSAGE_LEN],
+            len: 0,
+        }
+    }
+
+    /// Appends a string to the message.
+    pub const fn str(mut self, str: &str) -> Self {
+        let bytes = str.as_bytes();
+        let mut end = bytes.len();
+        if end > MAX_MESSAGE_LEN - self.len {
+            end = MAX_MESSAGE_LEN - self.len;
+            // Do not cut a multi-byte character in half, to keep the message valid UTF-8
+            while end > 0 && bytes[end] & 0b1100_0000 == 0b1000_0000 {
+                end -= 1;
+            }
+        }
+        let mut i = 0;
+        while i < end {
+            self.buf[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+
+    /// Appends the decimal representation of an unsigned integer to the message.
+    pub const fn unsigned(self, val: u128) -> Self {
+        // u128::MAX has 39 digits
+        let mut digits = [0u8; 39];
+        let mut start = digits.len();
+        let mut rest = val;
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (rest % 10) as u8;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        let bytes = digits.split_at(start).1;
+        // SAFETY: Only ASCII digits were written
+        self.str(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Appends the decimal representation of a signed integer to the message.
+    pub const fn signed(self, val: i128) -> Self {
+        if val < 0 {
+            self.str("-").unsigned(val.unsigned_abs())
+        } else {
+            self.unsigned(val as u128)
+        }
+    }
+
+    /// The message built so far.
+    pub const fn as_str(&self) -> &str {
+        // SAFETY: Only whole `str`s are ever written into the buffer
+        unsafe {
+            core::str::from_utf8_unchecked(core::slice::from_raw_parts(self.buf.as_ptr(), self.len))
+        }
+    }
+
+    /// Panics with this message.
+    ///
+    /// When called at compile-time, this results in a compile error showing the message.
+    pub const fn panic(&self) -> ! {
+        panic!("{}", self.as_str())
+    }
+}
+
+impl Default for ConstMessage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -4,21 +4,31 @@
 #[cfg(test)]
 extern crate std;
 use core::ffi::CStr;
-#[cfg(test)]
-use std::println;
 
 extern crate self as overloaded_literals;
+pub mod const_fmt;
+pub mod type_bigint;
+pub mod type_bytes;
+pub mod type_cstr;
+pub mod type_decimal;
 pub mod type_float;
 pub mod type_str;
 
+pub use type_bigint::TypeBigInt;
+pub use type_bytes::TypeBytes;
+pub use type_cstr::TypeCStr;
+pub use type_decimal::TypeDecimal;
 pub use type_float::TypeFloat;
 pub use type_str::TypeStr;
 
+use const_fmt::ConstMessage;
 use core::num::Wrapping;
 use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize};
 use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use core::time::Duration;
+use type_decimal::Decimal;
 
-/// Attribute macro to overload literals in the function it is used on.
+/// Attribute macro to overload literals in the function, impl block, trait or module it is used on.
 ///
 /// # Usage
 ///
@@ -37,14 +47,524 @@ use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZ
 ///
 /// - Any *unsigned* integer literal like `1337` is rewritten to [`FromLiteralUnsigned::<1337>::into_self()`](FromLiteralUnsigned)
 /// - Any *signed* integer literal like `-4200` is rewritten to [`FromLiteralSigned::<-4200>::into_self()`](FromLiteralSigned)
+/// - Hexadecimal, octal and binary integer literals like `0xFF` are rewritten to [`FromLiteralRadix::<0xFF, 16, 2>::into_self()`](FromLiteralRadix)
+/// - Integer literals too large for a `u128` or `i128` are rewritten to [`FromLiteralBigInt::<TBigInt>::into_self()`](FromLiteralBigInt)
 /// - Any `str` literal like `"hello"` is rewritten to [`FromLiteralStr::<"hello">::into_self()`](FromLiteralStr)
+/// - Any `char` literal like `'x'` is rewritten to [`FromLiteralChar::<'x'>::into_self()`](FromLiteralChar)
+/// - Any byte literal like `b'x'` is rewritten to [`FromLiteralByte::<b'x'>::into_self()`](FromLiteralByte)
+/// - Any byte string literal like `b"hello"` is rewritten to [`FromLiteralByteStr::<b"hello">::into_self()`](FromLiteralByteStr)
+/// - Any C string literal like `c"hello"` is rewritten to [`FromLiteralCStr::<c"hello">::into_self()`](FromLiteralCStr)
+/// - Integer, float and `str` literals with a custom suffix like `30ms` are rewritten to [`FromLiteralSuffixedUnsigned::<"ms", 30>::into_self()`](FromLiteralSuffixedUnsigned) (and similar)
+///
+/// # Choosing which literals are overloaded
+///
+/// By default, integer, float, `bool`, `str`, `char`, byte, byte string and C string literals are all overloaded.
+/// To only overload some kinds of literals, list them as arguments to the attribute.
+/// The other literals are left as plain primitives and infer their types as usual:
+///
+/// ```rust
+/// use overloaded_literals::overloaded_literals;
+/// use core::num::NonZeroU8;
+///
+/// #[overloaded_literals(int)]
+/// fn example() {
+///     let x: NonZeroU8 = 10; // <- overloaded
+///     let ratio = 0.5; // <- a plain float, which defaults to `f64`
+///     let enabled = true; // <- a plain `bool`
+///     assert_eq!(x.get(), 10);
+///     assert_eq!(ratio * 2.0, 1.0);
+///     assert!(enabled);
+/// }
+/// example()
+/// ```
+///
+/// The supported literal kinds are:
+/// - `int`: unsigned and negative integer literals
+/// - `float`: (negative) float literals
+/// - `bool`: `true` and `false`
+/// - `str`: string literals
+/// - `char`: character literals
+/// - `byte`: byte literals
+/// - `byte_str`: byte string literals
+/// - `c_str`: C string literals
+///
+/// # Re-exporting the macro
+///
+/// The generated code refers to this crate as `::overloaded_literals`.
+/// If it is used through a renamed dependency or a re-export from another crate,
+/// pass the path under which this crate can be reached as the `crate` argument
+/// (or, for [ol!], as a `crate = path;` prefix):
+///
+/// ```rust
+/// pub mod facade {
+///     pub use overloaded_literals::*;
+/// }
+///
+/// use facade::{ol, overloaded_literals};
+/// use core::num::NonZeroU8;
+///
+/// #[overloaded_literals(crate = crate::facade)]
+/// fn example() -> (NonZeroU8, &'static str) {
+///     (10, "hello")
+/// }
+///
+/// fn main() {
+///     let x: NonZeroU8 = ol!(crate = crate::facade; 42);
+///     assert_eq!(x.get(), 42);
+///     assert_eq!(example().0.get(), 10);
+/// }
+/// ```
+///
+/// # Opting out with `#[no_overload]`
+///
+/// Any expression, statement, nested block, match arm or item inside the annotated code
+/// can be marked with `#[no_overload]`.
+/// Literals inside it are left as-is, and the attribute itself is removed from the output:
+///
+/// ```rust
+/// use overloaded_literals::overloaded_literals;
+/// use core::num::NonZeroU8;
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let step: NonZeroU8 = 2;
+///     #[no_overload]
+///     let mut counter = 0; // <- A plain integer, which defaults to `i32`
+///     #[no_overload]
+///     {
+///         counter += 1;
+///     }
+///     let total = counter + #[no_overload] 10;
+///     assert_eq!(step.get(), 2);
+///     assert_eq!(total, 11);
+/// }
+/// example()
+/// ```
+///
+/// Literals with a type suffix (like `10usize`) are not overloaded either.
+/// Neither are literals in positions that require a constant, such as
+/// array lengths, const generic arguments, patterns, enum discriminants and attributes:
+///
+/// ```rust
+/// use overloaded_literals::overloaded_literals;
+/// use core::num::NonZeroU8;
+///
+/// #[overloaded_literals]
+/// fn example(bytes: [u8; 4]) -> usize {
+///     let ones: [NonZeroU8; 16] = [1; 16];
+///     #[cfg(not(feature = "nonexistent"))]
+///     let size = core::mem::size_of::<[u8; 2]>();
+///     match bytes.len() {
+///         0..=9 => size + ones.len(),
+///         _ => size,
+///     }
+/// }
+/// assert_eq!(example([1, 2, 3, 4]), 18);
+/// ```
+///
+/// # Macro invocations
+///
+/// Literals inside the arguments of macro invocations like `vec![1, 2, 3]` or `assert_eq!(x, 6)` are overloaded as well,
+/// as long as the arguments can be parsed as comma-separated expressions:
+///
+/// ```rust
+/// use overloaded_literals::overloaded_literals;
+/// use core::num::NonZeroU8;
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let numbers: Vec<NonZeroU8> = vec![1, 2, 3];
+///     let sum = numbers.iter().fold(1, |acc: NonZeroU8, x| acc.saturating_add(x.get()));
+///     assert_eq!(sum, 7, "sum should be {}", "seven");
+///     println!("The sum is {}", sum);
+/// }
+/// example()
+/// ```
+///
+/// - Format strings (like the `"sum should be {}"` above) are always left as-is.
+///   So are literals passed directly as (named) formatting arguments or to `dbg!`, as their type could never be inferred.
+/// - Only the first argument of `matches!` is overloaded, since the other is a pattern.
+/// - Macros which need actual literal tokens, like `concat!`, `stringify!`, `include_str!` or `asm!`, are left alone.
+/// - For other macros, string literal arguments are left alone, since they are most likely format strings.
+///
+/// # Literal patterns
+///
+/// Patterns can only contain real constants, so by default literals in patterns are left alone.
+/// Pass the `patterns` argument to also overload the literals (and literal ranges like `1..=9`) in
+/// the patterns of `match` arms and `if let` expressions.
+/// Each literal sub-pattern is then replaced by a fresh binding, which is compared against the overloaded literal in the arm's guard.
+/// This allows matching on types that do not have (public) constants, as long as they implement `PartialEq` (and `PartialOrd` for ranges):
+///
+/// ```rust
+/// use overloaded_literals::overloaded_literals;
+/// use core::num::NonZeroU8;
+///
+/// #[overloaded_literals(int, patterns)]
+/// fn describe(count: Option<NonZeroU8>) -> &'static str {
+///     match count {
+///         None => "none",
+///         Some(1) => "one",
+///         Some(2..=9) => "a few",
+///         Some(_) => "many",
+///     }
+/// }
+///
+/// #[overloaded_literals(int, patterns)]
+/// fn is_one(count: Option<NonZeroU8>) -> bool {
+///     if let Some(1) = count { true } else { false }
+/// }
+///
+/// // Matching through a reference works as well:
+/// #[overloaded_literals(int, patterns)]
+/// fn is_small(count: &Option<NonZeroU8>) -> bool {
+///     match count {
+///         Some(1..=3) => true,
+///         _ => false,
+///     }
+/// }
+/// # assert_eq!(describe(None), "none");
+/// # assert_eq!(describe(NonZeroU8::new(1)), "one");
+/// # assert_eq!(describe(NonZeroU8::new(5)), "a few");
+/// # assert_eq!(describe(NonZeroU8::new(10)), "many");
+/// # assert!(is_one(NonZeroU8::new(1)));
+/// # assert!(!is_one(NonZeroU8::new(2)));
+/// # assert!(is_small(&NonZeroU8::new(2)));
+/// # assert!(!is_small(&NonZeroU8::new(4)));
+/// # assert!(!is_small(&None));
+/// ```
+///
+/// Because the comparisons happen in guards, the compiler can no longer check these arms for exhaustiveness,
+/// so a `match` on e.g. a `bool` will need a catch-all `_` arm.
+/// Or-patterns which mix literals with other patterns (like `Some(1) | None`) and `x @ 1` bindings are left as-is.
+///
+/// # Array literals
+///
+/// Pass the `arrays` argument to also rewrite array expressions like `[1, 2, 3]` to [`FromLiteralArray::<_, 3>::into_self([1, 2, 3])`](FromLiteralArray),
+/// after overloading their elements.
+/// This allows building fixed-capacity vectors, non-empty lists and the like from array literals, with a compile-time check of the length.
+/// This is opt-in because, like any other overloaded literal, an array literal then needs a known target type.
+/// Repeat expressions like `[0; 4]` are left as-is, as are array literals in const contexts.
+///
+/// # Literals which are not overloaded
+///
+/// Literals with a built-in suffix like `10usize` or `1.5f32` are silently left alone.
+/// Pass the `strict` argument to turn these (and literals of a kind this crate cannot overload at all) into compile errors,
+/// making sure that such literals are either rewritten or explicitly marked with `#[no_overload]`:
+///
+/// ```compile_fail
+/// use overloaded_literals::overloaded_literals;
+///
+/// #[overloaded_literals(strict)]
+/// fn example() {
+///     let index = 10usize; // <- compile error 'literals with the built-in suffix `usize` are not overloaded (denied by `strict`)'
+/// }
+/// ```
+///
+/// `strict` does not affect literals the macro never looks at:
+/// literals of kinds which were not selected with the arguments (like a string literal in `#[overloaded_literals(int, strict)]`),
+/// and literals in positions which are never rewritten (like array repeat lengths, const generic arguments,
+/// and patterns unless `patterns` is passed).
+///
+/// # Impl blocks and traits
+///
+/// Instead of annotating every method separately, the attribute can also be put on a whole `impl` block or `trait` definition.
+/// This rewrites the literals inside every method body (including default method bodies of traits):
+///
+/// ```rust
+/// use overloaded_literals::overloaded_literals;
+/// use core::num::NonZeroU8;
+///
+/// pub struct Retries(NonZeroU8);
+///
+/// #[overloaded_literals]
+/// impl Retries {
+///     pub fn few() -> Self {
+///         Retries(3)
+///     }
+///
+///     pub fn many() -> Self {
+///         Retries(100)
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// pub trait Limit {
+///     fn limit(&self) -> NonZeroU8 {
+///         10
+///     }
+/// }
+/// # assert_eq!(Retries::few().0.get(), 3);
+/// # assert_eq!(Retries::many().0.get(), 100);
+/// ```
+///
+/// # Modules
+///
+/// Putting the attribute on an inline module opts in every function, impl block, trait and nested module inside it:
+///
+/// ```rust
+/// use overloaded_literals::overloaded_literals;
+///
+/// #[overloaded_literals]
+/// mod config {
+///     use core::num::NonZeroU16;
+///
+///     pub fn port() -> NonZeroU16 {
+///         8080
+///     }
+///
+///     pub mod limits {
+///         use core::num::NonZeroU8;
+///
+///         pub fn max_connections() -> NonZeroU8 {
+///             64
+///         }
+///     }
+/// }
+/// # assert_eq!(config::port().get(), 8080);
+/// # assert_eq!(config::limits::max_connections().get(), 64);
+/// ```
+///
+/// Nested items that carry their own `#[overloaded_literals]` attribute are left to be expanded by that attribute.
+///
+///
+/// # Const contexts
+///
+/// Since `into_self()` is not a `const fn`, literals inside the initializers of `const` and `static` items (including associated consts),
+/// inside inline `const { ... }` blocks and inside the bodies of `const fn`s are built using the associated `VALUE`
+/// of the sibling traits [ConstFromLiteralUnsigned], [ConstFromLiteralSigned], [ConstFromLiteralRadix], [ConstFromLiteralBigInt], [ConstFromLiteralFloat], [ConstFromLiteralDecimal], [ConstFromLiteralBool], [ConstFromLiteralStr], [ConstFromLiteralChar], [ConstFromLiteralByte], [ConstFromLiteralByteStr] and [ConstFromLiteralCStr] instead.
+/// These are implemented for all types this crate implements the `FromLiteral*` traits for.
+/// See [ConstFromLiteralUnsigned] for how to implement them for your own types.
 pub use overloaded_literals_macro::overloaded_literals;
 
+/// Function-like macro to overload the literals in a single expression (or `{ ... }` block).
+///
+/// The rewriting is the same as that of the [macro@overloaded_literals] attribute,
+/// but restricted to the given expression.
+/// This is useful when only a single literal should be overloaded,
+/// and overloading the whole function would break type inference for the other literals,
+/// or in places where an attribute cannot be put, such as inside closures:
+///
+/// ```rust
+/// use overloaded_literals::ol;
+/// use core::ffi::CStr;
+/// use core::num::NonZeroU32;
+///
+/// fn example() {
+///     let count = 10; // <- A normal `i32`
+///     let nonzero: NonZeroU32 = ol!(42);
+///     let name: &CStr = ol!("overloaded\0");
+///     let make = |x: u32| -> NonZeroU32 { ol!({ let extra: NonZeroU32 = 1; extra.saturating_add(x) }) };
+///     assert_eq!(count + 1, 11);
+///     assert_eq!(nonzero.get(), 42);
+///     assert_eq!(name.to_bytes(), b"overloaded");
+///     assert_eq!(make(2).get(), 3);
+/// }
+/// example()
+/// ```
+///
+/// A function-like macro cannot know whether it is used inside a const context.
+/// In the initializers of `const` and `static` items (or other [const contexts](macro@overloaded_literals#const-contexts)),
+/// write `ol!(const; expr)` to build the literals using the `ConstFromLiteral*` traits instead:
+///
+/// ```rust
+/// use overloaded_literals::ol;
+/// use core::num::{NonZeroI8, NonZeroU8};
+///
+/// static RETRIES: NonZeroU8 = ol!(const; 5);
+/// const OFFSET: NonZeroI8 = ol!(const; -3);
+///
+/// assert_eq!(RETRIES.get(), 5);
+/// assert_eq!(OFFSET.get(), -3);
+/// ```
+///
+/// When combined with `crate = path;`, that comes first: `ol!(crate = path; const; expr)`.
+pub use overloaded_literals_macro::ol;
+
+/// Names the type which the [macro@overloaded_literals] macro builds for a string literal.
+///
+/// This allows implementing [FromLiteralStr] (or [FromLiteralByteStr], [FromLiteralCStr])
+/// for only particular literals, without needing to depend on the details of the type-level representation:
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, typestr, FromLiteralStr};
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub enum Method {
+///     Get,
+///     Post,
+/// }
+///
+/// impl FromLiteralStr<typestr!("GET")> for Method {
+///     const VALID_LITERAL: &'static str = "GET";
+///     fn into_self() -> Self {
+///         Method::Get
+///     }
+/// }
+///
+/// impl FromLiteralStr<typestr!("POST")> for Method {
+///     const VALID_LITERAL: &'static str = "POST";
+///     fn into_self() -> Self {
+///         Method::Post
+///     }
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     let get: Method = "GET";
+///     let post: Method = "POST";
+///     // let put: Method = "PUT"; // <- This would cause a compile error :-)
+///     assert_eq!(get, Method::Get);
+///     assert_eq!(post, Method::Post);
+/// }
+/// example()
+/// ```
+///
+/// Byte string and C string literals are supported as well: `typestr!(b"\x89PNG")`, `typestr!(c"name")`.
+/// Like [ol!], the path to this crate can be overridden with `typestr!(crate = path; "GET")`.
+///
+/// Building the type-level representation by hand is not needed, and is always checked:
+/// [TypeStr::STR] results in a compile error if the bytes are not valid UTF-8.
+/// ```compile_fail
+/// use overloaded_literals::{type_bytes::Chunk, TypeStr};
+///
+/// let invalid: &str = <Chunk<1, 0xFF> as TypeStr>::STR;
+/// ```
+pub use overloaded_literals_macro::typestr;
+
+/// Not part of the public API. Used by the code generated by the [macro@overloaded_literals] macro.
+#[doc(hidden)]
+pub mod __private {
+    use crate::{
+        ConstFromLiteralBigInt, ConstFromLiteralBool, ConstFromLiteralByte,
+        ConstFromLiteralByteStr, ConstFromLiteralCStr, ConstFromLiteralChar, ConstFromLiteralFloat,
+        ConstFromLiteralRadix, ConstFromLiteralSigned, ConstFromLiteralStr,
+        ConstFromLiteralSuffixedFloat, ConstFromLiteralSuffixedSigned, ConstFromLiteralSuffixedStr,
+        ConstFromLiteralSuffixedUnsigned, ConstFromLiteralUnsigned, TypeBigInt, TypeBytes,
+        TypeCStr, TypeFloat, TypeStr,
+    };
+
+    #[inline]
+    pub fn pattern_eq<T: PartialEq>(val: &T, lit: T) -> bool {
+        *val == lit
+    }
+
+    #[inline]
+    pub fn pattern_ge<T: PartialOrd>(val: &T, lit: T) -> bool {
+        *val >= lit
+    }
+
+    #[inline]
+    pub fn pattern_le<T: PartialOrd>(val: &T, lit: T) -> bool {
+        *val <= lit
+    }
+
+    #[inline]
+    pub fn pattern_lt<T: PartialOrd>(val: &T, lit: T) -> bool {
+        *val < lit
+    }
+
+    // Used inside const contexts, since `into_self()` is not a `const fn`.
+    // Going through a function (rather than `<T as Trait>::VALUE`) lets the target type be inferred.
+
+    #[inline]
+    pub const fn const_unsigned<const LIT: u128, T: ConstFromLiteralUnsigned<LIT>>() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_signed<const LIT: i128, T: ConstFromLiteralSigned<LIT>>() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_bool<const LIT: bool, T: ConstFromLiteralBool<LIT>>() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_char<const LIT: char, T: ConstFromLiteralChar<LIT>>() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_byte<const LIT: u8, T: ConstFromLiteralByte<LIT>>() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_radix<
+        const LIT: u128,
+        const RADIX: u32,
+        const DIGITS: u32,
+        T: ConstFromLiteralRadix<LIT, RADIX, DIGITS>,
+    >() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_big_int<TBigInt: TypeBigInt, T: ConstFromLiteralBigInt<TBigInt>>() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_float<TFloat: TypeFloat, T: ConstFromLiteralFloat<TFloat>>() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_str<TStr: TypeStr, T: ConstFromLiteralStr<TStr>>() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_suffixed_unsigned<
+        Suffix: TypeStr,
+        const LIT: u128,
+        T: ConstFromLiteralSuffixedUnsigned<Suffix, LIT>,
+    >() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_suffixed_signed<
+        Suffix: TypeStr,
+        const LIT: i128,
+        T: ConstFromLiteralSuffixedSigned<Suffix, LIT>,
+    >() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_suffixed_float<
+        Suffix: TypeStr,
+        TFloat: TypeFloat,
+        T: ConstFromLiteralSuffixedFloat<Suffix, TFloat>,
+    >() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_suffixed_str<
+        Suffix: TypeStr,
+        TStr: TypeStr,
+        T: ConstFromLiteralSuffixedStr<Suffix, TStr>,
+    >() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_c_str<TCStr: TypeCStr, T: ConstFromLiteralCStr<TCStr>>() -> T {
+        T::VALUE
+    }
+
+    #[inline]
+    pub const fn const_byte_str<TBytes: TypeBytes, T: ConstFromLiteralByteStr<TBytes>>() -> T {
+        T::VALUE
+    }
+}
+
 mod sealed {
     pub trait Sealed {}
-    impl<const VAL: u8> Sealed for crate::type_str::Byte<VAL> {}
-    impl Sealed for tlist::TNil {}
-    impl<H, T: tlist::TList> Sealed for tlist::TCons<H, T> {}
 }
 
 /// Build your datatype from a `&'static str` literal.
@@ -115,6 +635,12 @@ mod sealed {
 /// ```
 ///
 /// Another good example is [the implementation for CStr](#impl-FromLiteralStr<TStr>-for-%26%27static%20CStr) which is included with the library.
+#[diagnostic::on_unimplemented(
+    message = "string literals cannot be used for `{Self}`",
+    label = "this string literal is used here as a `{Self}`",
+    note = "to allow this, implement `FromLiteralStr<TStr>` for `{Self}`",
+    note = "to keep this a plain `&'static str`, mark it with `#[no_overload]`"
+)]
 pub trait FromLiteralStr<TStr: TypeStr> {
     /// The definition of `VALID_LITERAL` is evaluated at compile-time.
     ///
@@ -141,8 +667,16 @@ pub trait FromLiteralStr<TStr: TypeStr> {
     fn into_self() -> Self;
 }
 
+/// Build your datatype from a `&'static str` literal inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralStr<TStr: TypeStr>: FromLiteralStr<TStr> {
+    /// The value built from [VALID_LITERAL](FromLiteralStr::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
 // Base definition
-impl<'a, Str: TypeStr> FromLiteralStr<Str> for &'a str {
+impl<Str: TypeStr> FromLiteralStr<Str> for &str {
     const VALID_LITERAL: &'static str = Str::STR;
 
     #[inline]
@@ -151,6 +685,10 @@ impl<'a, Str: TypeStr> FromLiteralStr<Str> for &'a str {
     }
 }
 
+impl<Str: TypeStr> ConstFromLiteralStr<Str> for &str {
+    const VALUE: Self = <Self as FromLiteralStr<Str>>::VALID_LITERAL;
+}
+
 // Build owned strings directly from string literals
 // impl<Str: TypeStr> FromLiteralStr<Str> for String {
 //     const VALID_LITERAL: &'static str = Str::STR;
@@ -184,6 +722,12 @@ impl<'a, Str: TypeStr> FromLiteralStr<Str> for &'a str {
 ///                                              runtime
 /// ```
 ///
+#[diagnostic::on_unimplemented(
+    message = "integer literals cannot be used for `{Self}`",
+    label = "the integer literal `{LIT}` is used here as a `{Self}`",
+    note = "to allow this, implement `FromLiteralUnsigned<LIT>` for `{Self}`",
+    note = "to keep this a plain integer, mark it with `#[no_overload]` or give it a suffix like `{LIT}u64`"
+)]
 pub trait FromLiteralUnsigned<const LIT: u128> {
     /// The definition of `VALID_LITERAL` is evaluated at compile-time.
     ///
@@ -205,6 +749,21 @@ pub trait FromLiteralUnsigned<const LIT: u128> {
     /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
     /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
     fn into_self() -> Self;
+
+    // NOTE: Every `FromLiteralUnsigned` type implements `FromLiteralRadix` through a blanket impl,
+    // so coherence prevents the built-in signed integers from implementing it themselves.
+    // Instead, they override these to interpret prefixed literals as bit patterns.
+    #[doc(hidden)]
+    const RADIX_VALID_LITERAL: u128 = <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL;
+
+    #[doc(hidden)]
+    #[inline]
+    fn radix_into_self() -> Self
+    where
+        Self: Sized,
+    {
+        <Self as FromLiteralUnsigned<LIT>>::into_self()
+    }
 }
 
 /// Build your datatype from a signed integer literal (-1, -2, -3, ...).
@@ -234,6 +793,12 @@ pub trait FromLiteralUnsigned<const LIT: u128> {
 ///                                             runtime
 /// ```
 ///
+#[diagnostic::on_unimplemented(
+    message = "negative integer literals cannot be used for `{Self}`",
+    label = "the integer literal `{LIT}` is used here as a `{Self}`",
+    note = "to allow this, implement `FromLiteralSigned<LIT>` for `{Self}`",
+    note = "to keep this a plain integer, mark it with `#[no_overload]` or give it a suffix like `{LIT}i64`"
+)]
 pub trait FromLiteralSigned<const LIT: i128> {
     /// The definition of `VALID_LITERAL` is evaluated at compile-time.
     ///
@@ -257,39 +822,309 @@ pub trait FromLiteralSigned<const LIT: i128> {
     fn into_self() -> Self;
 }
 
-macro_rules! unsigned_impl {
-    ($type:ty) => {
-        impl<const LIT: u128> FromLiteralUnsigned<LIT> for $type {
-            const VALID_LITERAL: u128 = {
-                let min = <$type>::MIN as u128;
-                let max = <$type>::MAX as u128;
-                assert!(LIT >= min && LIT <= max, "Out of range integer literal");
-                LIT
-            };
-
-            #[inline]
-            fn into_self() -> Self {
-                <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as $type
-            }
-        }
-    };
-}
-
-macro_rules! signed_impl {
-    ($type:ty) => {
-        impl<const LIT: i128> FromLiteralSigned<LIT> for $type {
-            const VALID_LITERAL: i128 = {
-                let min = <$type>::MIN as i128;
-                let max = <$type>::MAX as i128;
-                assert!(LIT >= min && LIT <= max, "Out of range integer literal");
-                LIT
-            };
-
-            #[inline]
+/// Build your datatype from an unsigned integer literal inside const contexts.
+///
+/// [`into_self()`](FromLiteralUnsigned::into_self) is not a `const fn`,
+/// so inside the initializers of `const` and `static` items, inline `const` blocks and `const fn` bodies,
+/// the [macro@overloaded_literals] macro uses the associated [VALUE](ConstFromLiteralUnsigned::VALUE) of this trait instead.
+///
+/// Implementing it is optional; types which only implement [FromLiteralUnsigned] can still be used everywhere else.
+///
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, ConstFromLiteralUnsigned, FromLiteralUnsigned};
+/// use core::num::NonZeroU32;
+///
+/// pub struct Percentage(u8);
+///
+/// impl<const LIT: u128> FromLiteralUnsigned<LIT> for Percentage {
+///     const VALID_LITERAL: u128 = {
+///         assert!(LIT <= 100, "Percentage literal must be at most 100");
+///         LIT
+///     };
+///
+///     fn into_self() -> Self {
+///         <Self as ConstFromLiteralUnsigned<LIT>>::VALUE
+///     }
+/// }
+///
+/// impl<const LIT: u128> ConstFromLiteralUnsigned<LIT> for Percentage {
+///     const VALUE: Self = Percentage(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u8);
+/// }
+///
+/// #[overloaded_literals]
+/// const fn backoff_limit() -> NonZeroU32 {
+///     60
+/// }
+///
+/// #[overloaded_literals]
+/// fn example() {
+///     const MAX_RETRIES: NonZeroU32 = 5;
+///     static THRESHOLD: Percentage = 75;
+///     assert_eq!(MAX_RETRIES.get(), 5);
+///     assert_eq!(THRESHOLD.0, 75);
+///     assert_eq!(backoff_limit().get(), 60);
+/// #   const RATIO: f32 = -0.5;
+/// #   const OFFSET: core::num::NonZeroI8 = -3;
+/// #   const NAME: &core::ffi::CStr = "retry\0";
+/// #   assert_eq!(RATIO, -0.5);
+/// #   assert_eq!(OFFSET.get(), -3);
+/// #   assert_eq!(NAME.to_bytes().len(), 5);
+/// }
+/// example()
+/// ```
+pub trait ConstFromLiteralUnsigned<const LIT: u128>: FromLiteralUnsigned<LIT> + Sized {
+    /// The value built from [VALID_LITERAL](FromLiteralUnsigned::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+
+    // NOTE: See `FromLiteralUnsigned::RADIX_VALID_LITERAL`
+    #[doc(hidden)]
+    const RADIX_VALUE: Self = <Self as ConstFromLiteralUnsigned<LIT>>::VALUE;
+}
+
+/// Build your datatype from a signed integer literal inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralSigned<const LIT: i128>: FromLiteralSigned<LIT> {
+    /// The value built from [VALID_LITERAL](FromLiteralSigned::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+/// Build your datatype from a hexadecimal, octal or binary integer literal, knowing how it was written.
+///
+/// The [macro@overloaded_literals] macro turns integer literals with a radix prefix like
+/// ```compile_only
+/// 0b0000_1111
+/// ```
+/// into calls to
+///
+/// ```compile_only
+/// FromLiteralRadix::<0b0000_1111, 2, 8>::into_self()
+/// ```
+/// where the second argument is the radix (`2`, `8` or `16`) and the third the number of written digits
+/// (including leading zeroes but not counting underscores).
+///
+/// This allows bitfield and register types to require literals of an exact width,
+/// and signed types to interpret hexadecimal literals as bit patterns.
+///
+/// Every type implementing [FromLiteralUnsigned] automatically implements `FromLiteralRadix`,
+/// so for most types the radix does not matter.
+/// Decimal literals and negative literals are always passed to [FromLiteralUnsigned] and [FromLiteralSigned].
+///
+/// The exception are the built-in signed integer types `i8`..`isize` and `NonZeroI8`..`NonZeroIsize`
+/// (and their `Wrapping` variants): they interpret a prefixed literal
+/// as the two's complement bit pattern of the type's full width,
+/// so `0xFF` as an `i8` is `-1`, and `0x100` is a compile error.
+///
+/// # Example
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralRadix};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub struct Bits8(u8);
+///
+/// impl<const LIT: u128, const RADIX: u32, const DIGITS: u32> FromLiteralRadix<LIT, RADIX, DIGITS> for Bits8 {
+///     const VALID_LITERAL: u128 = {
+///         if RADIX != 2 || DIGITS != 8 {
+///             panic!("Bits8 literals need to be written as exactly 8 binary digits, like `0b0000_1111`")
+///         }
+///         LIT
+///     };
+///
+///     fn into_self() -> Self {
+///         let val = <Self as FromLiteralRadix<LIT, RADIX, DIGITS>>::VALID_LITERAL;
+///         Bits8(val as u8)
+///     }
+/// }
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub struct Word(i16);
+///
+/// impl<const LIT: u128, const RADIX: u32, const DIGITS: u32> FromLiteralRadix<LIT, RADIX, DIGITS> for Word {
+///     const VALID_LITERAL: u128 = {
+///         if RADIX != 16 || LIT > u16::MAX as u128 {
+///             panic!("Word literals need to be written as at most 4 hexadecimal digits")
+///         }
+///         LIT
+///     };
+///
+///     fn into_self() -> Self {
+///         let val = <Self as FromLiteralRadix<LIT, RADIX, DIGITS>>::VALID_LITERAL;
+///         Word(val as u16 as i16) // <- Interpreted as bit pattern
+///     }
+/// }
+/// // Usage:
+/// #[overloaded_literals(int)]
+/// fn example() {
+///    let mask: Bits8 = 0b0000_1111;
+///    let minus_one: Word = 0xFFFF;
+///    let byte: u8 = 0xFF; // <- Types implementing `FromLiteralUnsigned` accept any radix
+///    let bits: i8 = 0xFF; // <- Signed integers use the bit pattern
+///    // let boom: Bits8 = 0b1111; // <- This would cause a compile error :-)
+///    // let boom: Bits8 = 15; // <- And so would this
+///    assert_eq!(mask, Bits8(15u8));
+///    assert_eq!(minus_one, Word(-1i16));
+///    assert_eq!(byte, 255u8);
+///    assert_eq!(bits, -1i8);
+/// }
+/// example()
+/// ```
+pub trait FromLiteralRadix<const LIT: u128, const RADIX: u32, const DIGITS: u32> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `LIT`, `RADIX` and `DIGITS`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: u128;
+
+    /// Turns a [VALID_LITERAL](FromLiteralRadix::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from a hexadecimal, octal or binary integer literal inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralRadix<const LIT: u128, const RADIX: u32, const DIGITS: u32>:
+    FromLiteralRadix<LIT, RADIX, DIGITS>
+{
+    /// The value built from [VALID_LITERAL](FromLiteralRadix::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+impl<T, const LIT: u128, const RADIX: u32, const DIGITS: u32> FromLiteralRadix<LIT, RADIX, DIGITS>
+    for T
+where
+    T: FromLiteralUnsigned<LIT>,
+{
+    const VALID_LITERAL: u128 = <T as FromLiteralUnsigned<LIT>>::RADIX_VALID_LITERAL;
+
+    #[inline]
+    fn into_self() -> Self {
+        <T as FromLiteralUnsigned<LIT>>::radix_into_self()
+    }
+}
+
+impl<T, const LIT: u128, const RADIX: u32, const DIGITS: u32>
+    ConstFromLiteralRadix<LIT, RADIX, DIGITS> for T
+where
+    T: ConstFromLiteralUnsigned<LIT>,
+{
+    const VALUE: Self = <T as ConstFromLiteralUnsigned<LIT>>::RADIX_VALUE;
+}
+
+/// Panics with a message like `literal 300 is out of range for u8 (0..=255)`.
+///
+/// `literal` contains the literal that was rejected, `min` and `max` are the bounds of `type_name`.
+/// For `non_zero` types, zero is excluded from the range.
+const fn out_of_range(
+    literal: ConstMessage,
+    type_name: &str,
+    min: i128,
+    max: u128,
+    non_zero: bool,
+) -> ! {
+    let msg = literal
+        .str(" is out of range for ")
+        .str(type_name)
+        .str(" (");
+    let msg = if !non_zero {
+        msg.signed(min).str("..=").unsigned(max)
+    } else if min == 0 {
+        msg.str("1..=").unsigned(max)
+    } else {
+        msg.signed(min).str("..=-1 or 1..=").unsigned(max)
+    };
+    msg.str(")").panic()
+}
+
+/// Checks that `literal` is a valid bit pattern for `type_name`, at most `max`.
+///
+/// Panics with a message like `literal 256 is out of range for i8 bit patterns (0..=255)`.
+const fn valid_bit_pattern(literal: u128, type_name: &str, max: u128, non_zero: bool) -> u128 {
+    if literal > max || (non_zero && literal == 0) {
+        let msg = ConstMessage::new().str("literal ").unsigned(literal);
+        out_of_range(msg, type_name, 0, max, non_zero)
+    }
+    literal
+}
+
+macro_rules! unsigned_impl {
+    ($type:ty) => {
+        unsigned_impl!($type, {}, {});
+    };
+    // Signed integers interpret prefixed literals as the bit pattern of `$bits_type`
+    ($type:ty, bit_pattern: $bits_type:ty) => {
+        unsigned_impl!($type, {
+            const RADIX_VALID_LITERAL: u128 = valid_bit_pattern(
+                LIT,
+                concat!(stringify!($type), " bit patterns"),
+                <$bits_type>::MAX as u128,
+                false,
+            );
+
+            #[inline]
+            fn radix_into_self() -> Self {
+                <Self as FromLiteralUnsigned<LIT>>::RADIX_VALID_LITERAL as $bits_type as $type
+            }
+        }, {
+            const RADIX_VALUE: Self =
+                <Self as FromLiteralUnsigned<LIT>>::RADIX_VALID_LITERAL as $bits_type as $type;
+        });
+    };
+    ($type:ty, {$($radix_items:tt)*}, {$($const_radix_items:tt)*}) => {
+        impl<const LIT: u128> FromLiteralUnsigned<LIT> for $type {
+            const VALID_LITERAL: u128 = {
+                // NOTE: `<$type>::MIN as u128` would wrap around for signed types
+                let max = <$type>::MAX as u128;
+                if LIT > max {
+                    let literal = ConstMessage::new().str("literal ").unsigned(LIT);
+                    out_of_range(literal, stringify!($type), <$type>::MIN as i128, max, false)
+                }
+                LIT
+            };
+
+            #[inline]
+            fn into_self() -> Self {
+                <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as $type
+            }
+
+            $($radix_items)*
+        }
+
+        impl<const LIT: u128> ConstFromLiteralUnsigned<LIT> for $type {
+            const VALUE: Self = <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as $type;
+
+            $($const_radix_items)*
+        }
+    };
+}
+
+macro_rules! signed_impl {
+    ($type:ty) => {
+        impl<const LIT: i128> FromLiteralSigned<LIT> for $type {
+            const VALID_LITERAL: i128 = {
+                let min = <$type>::MIN as i128;
+                let max = <$type>::MAX as i128;
+                if LIT < min || LIT > max {
+                    let literal = ConstMessage::new().str("literal ").signed(LIT);
+                    out_of_range(literal, stringify!($type), min, max as u128, false)
+                }
+                LIT
+            };
+
+            #[inline]
             fn into_self() -> Self {
                 <Self as FromLiteralSigned<LIT>>::VALID_LITERAL as $type
             }
         }
+
+        impl<const LIT: i128> ConstFromLiteralSigned<LIT> for $type {
+            const VALUE: Self = <Self as FromLiteralSigned<LIT>>::VALID_LITERAL as $type;
+        }
     };
 }
 
@@ -300,12 +1135,12 @@ unsigned_impl!(u64);
 unsigned_impl!(u128);
 unsigned_impl!(usize);
 
-unsigned_impl!(i8);
-unsigned_impl!(i16);
-unsigned_impl!(i32);
-unsigned_impl!(i64);
-unsigned_impl!(i128);
-unsigned_impl!(isize);
+unsigned_impl!(i8, bit_pattern: u8);
+unsigned_impl!(i16, bit_pattern: u16);
+unsigned_impl!(i32, bit_pattern: u32);
+unsigned_impl!(i64, bit_pattern: u64);
+unsigned_impl!(i128, bit_pattern: u128);
+unsigned_impl!(isize, bit_pattern: usize);
 
 signed_impl!(i8);
 signed_impl!(i16);
@@ -316,11 +1151,46 @@ signed_impl!(isize);
 
 macro_rules! nonzero_unsigned_impl {
     ($type:ty, $orig_type:ty) => {
+        nonzero_unsigned_impl!($type, $orig_type, {}, {});
+    };
+    // Signed integers interpret prefixed literals as the bit pattern of `$bits_type`
+    ($type:ty, $orig_type:ty, bit_pattern: $bits_type:ty) => {
+        nonzero_unsigned_impl!($type, $orig_type, {
+            const RADIX_VALID_LITERAL: u128 = valid_bit_pattern(
+                LIT,
+                concat!(stringify!($type), " bit patterns"),
+                <$bits_type>::MAX as u128,
+                true,
+            );
+
+            #[inline]
+            fn radix_into_self() -> Self {
+                let raw = <Self as FromLiteralUnsigned<LIT>>::RADIX_VALID_LITERAL as $bits_type as $orig_type;
+                // SAFETY: Bounds check happened at compile time
+                unsafe { <$type>::new_unchecked(raw) }
+            }
+        }, {
+            const RADIX_VALUE: Self = {
+                let raw = <Self as FromLiteralUnsigned<LIT>>::RADIX_VALID_LITERAL as $bits_type as $orig_type;
+                // SAFETY: Bounds check happened in RADIX_VALID_LITERAL
+                unsafe { <$type>::new_unchecked(raw) }
+            };
+        });
+    };
+    ($type:ty, $orig_type:ty, {$($radix_items:tt)*}, {$($const_radix_items:tt)*}) => {
         impl<const LIT: u128> FromLiteralUnsigned<LIT> for $type {
             const VALID_LITERAL: u128 = {
                 let max = <$orig_type>::MAX as u128;
-                assert!(LIT != 0, "NonZero integer literal was 0");
-                assert!(LIT <= max, "Out of range NonZero integer literal");
+                if LIT == 0 || LIT > max {
+                    let literal = ConstMessage::new().str("literal ").unsigned(LIT);
+                    out_of_range(
+                        literal,
+                        stringify!($type),
+                        <$orig_type>::MIN as i128,
+                        max,
+                        true,
+                    )
+                }
                 LIT
             };
 
@@ -330,6 +1200,18 @@ macro_rules! nonzero_unsigned_impl {
                 // SAFETY: Bounds check happened at compile time
                 unsafe { <$type>::new_unchecked(raw) }
             }
+
+            $($radix_items)*
+        }
+
+        impl<const LIT: u128> ConstFromLiteralUnsigned<LIT> for $type {
+            const VALUE: Self = {
+                let raw = <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as $orig_type;
+                // SAFETY: Bounds check happened in VALID_LITERAL
+                unsafe { <$type>::new_unchecked(raw) }
+            };
+
+            $($const_radix_items)*
         }
     };
 }
@@ -340,11 +1222,10 @@ macro_rules! nonzero_signed_impl {
             const VALID_LITERAL: i128 = {
                 let min = <$orig_type>::MIN as i128;
                 let max = <$orig_type>::MAX as i128;
-                assert!(LIT != 0, "NonZero integer literal was 0");
-                assert!(
-                    LIT >= min && LIT <= max,
-                    "Out of range NonZero integer literal"
-                );
+                if LIT == 0 || LIT < min || LIT > max {
+                    let literal = ConstMessage::new().str("literal ").signed(LIT);
+                    out_of_range(literal, stringify!($type), min, max as u128, true)
+                }
                 LIT
             };
 
@@ -355,6 +1236,14 @@ macro_rules! nonzero_signed_impl {
                 unsafe { <$type>::new_unchecked(raw) }
             }
         }
+
+        impl<const LIT: i128> ConstFromLiteralSigned<LIT> for $type {
+            const VALUE: Self = {
+                let raw = <Self as FromLiteralSigned<LIT>>::VALID_LITERAL as $orig_type;
+                // SAFETY: Bounds check happened in VALID_LITERAL
+                unsafe { <$type>::new_unchecked(raw) }
+            };
+        }
     };
 }
 
@@ -365,12 +1254,12 @@ nonzero_unsigned_impl!(NonZeroU64, u64);
 nonzero_unsigned_impl!(NonZeroU128, u128);
 nonzero_unsigned_impl!(NonZeroUsize, usize);
 
-nonzero_unsigned_impl!(NonZeroI8, i8);
-nonzero_unsigned_impl!(NonZeroI16, i16);
-nonzero_unsigned_impl!(NonZeroI32, i32);
-nonzero_unsigned_impl!(NonZeroI64, i64);
-nonzero_unsigned_impl!(NonZeroI128, i128);
-nonzero_unsigned_impl!(NonZeroIsize, isize);
+nonzero_unsigned_impl!(NonZeroI8, i8, bit_pattern: u8);
+nonzero_unsigned_impl!(NonZeroI16, i16, bit_pattern: u16);
+nonzero_unsigned_impl!(NonZeroI32, i32, bit_pattern: u32);
+nonzero_unsigned_impl!(NonZeroI64, i64, bit_pattern: u64);
+nonzero_unsigned_impl!(NonZeroI128, i128, bit_pattern: u128);
+nonzero_unsigned_impl!(NonZeroIsize, isize, bit_pattern: usize);
 nonzero_signed_impl!(NonZeroI8, i8);
 nonzero_signed_impl!(NonZeroI16, i16);
 nonzero_signed_impl!(NonZeroI32, i32);
@@ -385,6 +1274,13 @@ impl<T: FromLiteralUnsigned<LIT>, const LIT: u128> FromLiteralUnsigned<LIT> for
     fn into_self() -> Self {
         Wrapping(T::into_self())
     }
+
+    const RADIX_VALID_LITERAL: u128 = T::RADIX_VALID_LITERAL;
+
+    #[inline]
+    fn radix_into_self() -> Self {
+        Wrapping(T::radix_into_self())
+    }
 }
 
 impl<T: FromLiteralSigned<LIT>, const LIT: i128> FromLiteralSigned<LIT> for Wrapping<T> {
@@ -392,22 +1288,947 @@ impl<T: FromLiteralSigned<LIT>, const LIT: i128> FromLiteralSigned<LIT> for Wrap
 
     #[inline]
     fn into_self() -> Self {
-        Wrapping(T::into_self())
+        Wrapping(T::into_self())
+    }
+}
+
+impl<T: ConstFromLiteralUnsigned<LIT>, const LIT: u128> ConstFromLiteralUnsigned<LIT>
+    for Wrapping<T>
+{
+    const VALUE: Self = Wrapping(T::VALUE);
+
+    const RADIX_VALUE: Self = Wrapping(T::RADIX_VALUE);
+}
+
+impl<T: ConstFromLiteralSigned<LIT>, const LIT: i128> ConstFromLiteralSigned<LIT> for Wrapping<T> {
+    const VALUE: Self = Wrapping(T::VALUE);
+}
+
+/// Build your datatype from a boolean literal (`false` or `true`).
+///
+/// The [macro@overloaded_literals] macro turns boolean literals like
+/// ```compile_only
+/// true
+/// ```
+/// into calls to
+///
+/// ```compile_only
+/// FromLiteralBool::<true>::VALID_LITERAL::into_self()
+/// ```
+///
+///
+/// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
+/// where invalid input results in a compile error.
+///
+/// The second part (`into_self()`) runs at runtime, and is where you create your actual value,
+/// knowing that the input is guaranteed to be valid.
+///
+/// ```txt
+/// FromLiteralBool::<true>::VALID_LITERAL.into_self()
+/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///               compile time             ^^^^^^^^^^^
+///                                          runtime
+/// ```
+///
+#[diagnostic::on_unimplemented(
+    message = "boolean literals cannot be used for `{Self}`",
+    label = "the boolean literal `{LIT}` is used here as a `{Self}`",
+    note = "to allow this, implement `FromLiteralBool<LIT>` for `{Self}`",
+    note = "to keep this a plain `bool`, mark it with `#[no_overload]`"
+)]
+pub trait FromLiteralBool<const LIT: bool> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `LIT`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: bool;
+
+    /// Turns a [VALID_LITERAL](FromLiteralBool::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    ///
+    /// You have access to [VALID_LITERAL](FromLiteralBool::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralBool<LIT>>::VALID_LITERAL;`),
+    /// and should turn it into your desired value.
+    ///
+    /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
+    /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from a boolean literal inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralBool<const LIT: bool>: FromLiteralBool<LIT> {
+    /// The value built from [VALID_LITERAL](FromLiteralBool::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+impl<const LIT: bool> FromLiteralBool<LIT> for bool {
+    const VALID_LITERAL: bool = LIT;
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralBool<LIT>>::VALID_LITERAL
+    }
+}
+
+impl<const LIT: bool> ConstFromLiteralBool<LIT> for bool {
+    const VALUE: Self = <Self as FromLiteralBool<LIT>>::VALID_LITERAL;
+}
+
+/// Build your datatype from a `char` literal (`'a'`, `'7'`, `'🦀'`, ...).
+///
+/// The [macro@overloaded_literals] macro turns char literals like
+/// ```compile_only
+/// 'x'
+/// ```
+/// into calls to
+///
+/// ```compile_only
+/// FromLiteralChar::<'x'>::VALID_LITERAL::into_self()
+/// ```
+///
+/// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
+/// where invalid input results in a compile error.
+///
+/// The second part (`into_self()`) runs at runtime, and is where you create your actual value,
+/// knowing that the input is guaranteed to be valid.
+///
+/// ```txt
+/// FromLiteralChar::<'x'>::VALID_LITERAL.into_self()
+/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///               compile time            ^^^^^^^^^^^
+///                                         runtime
+/// ```
+///
+/// # Example
+/// As an example, consider a type representing a single hexadecimal digit:
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralChar};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub struct HexDigit(u8);
+///
+/// impl<const LIT: char> FromLiteralChar<LIT> for HexDigit {
+///     const VALID_LITERAL: char = {
+///         assert!(LIT.is_ascii_hexdigit(), "Invalid HexDigit literal; only 0-9, a-f and A-F are allowed.");
+///         LIT
+///     };
+///
+///     fn into_self() -> Self {
+///         let digit = <Self as FromLiteralChar<LIT>>::VALID_LITERAL;
+///         HexDigit(digit.to_digit(16).unwrap() as u8)
+///     }
+/// }
+/// // Usage:
+/// #[overloaded_literals]
+/// fn example() {
+///    let ten: HexDigit = 'a';
+///    let byte: u8 = 'z';
+///    let text: &str = '🦀';
+///    // let boom: HexDigit = 'g'; // <- This would cause a compile error :-)
+///    assert_eq!(ten, HexDigit(10));
+///    assert_eq!(byte, b'z');
+///    assert_eq!(text.len(), 4);
+/// }
+/// example()
+/// ```
+///
+/// Only ASCII characters can be turned into a `u8`:
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// #[overloaded_literals]
+/// fn example() {
+///    let byte: u8 = 'é';
+/// }
+/// example()
+/// ```
+pub trait FromLiteralChar<const LIT: char> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `LIT`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: char;
+
+    /// Turns a [VALID_LITERAL](FromLiteralChar::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    ///
+    /// You have access to [VALID_LITERAL](FromLiteralChar::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralChar<LIT>>::VALID_LITERAL;`),
+    /// and should turn it into your desired value.
+    ///
+    /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
+    /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from a `char` literal inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralChar<const LIT: char>: FromLiteralChar<LIT> {
+    /// The value built from [VALID_LITERAL](FromLiteralChar::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+impl<const LIT: char> FromLiteralChar<LIT> for char {
+    const VALID_LITERAL: char = LIT;
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralChar<LIT>>::VALID_LITERAL
+    }
+}
+
+impl<const LIT: char> ConstFromLiteralChar<LIT> for char {
+    const VALUE: Self = <Self as FromLiteralChar<LIT>>::VALID_LITERAL;
+}
+
+impl<const LIT: char> FromLiteralChar<LIT> for u8 {
+    const VALID_LITERAL: char = {
+        assert!(
+            LIT.is_ascii(),
+            "Only ASCII char literals can be turned into a u8"
+        );
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralChar<LIT>>::VALID_LITERAL as u8
+    }
+}
+
+impl<const LIT: char> ConstFromLiteralChar<LIT> for u8 {
+    const VALUE: Self = <Self as FromLiteralChar<LIT>>::VALID_LITERAL as u8;
+}
+
+impl<const LIT: char> FromLiteralChar<LIT> for u32 {
+    const VALID_LITERAL: char = LIT;
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralChar<LIT>>::VALID_LITERAL as u32
+    }
+}
+
+impl<const LIT: char> ConstFromLiteralChar<LIT> for u32 {
+    const VALUE: Self = <Self as FromLiteralChar<LIT>>::VALID_LITERAL as u32;
+}
+
+/// The UTF-8 encoding of `LIT`, stored in a constant so it can be borrowed for `'static`.
+struct CharStr<const LIT: char>;
+
+impl<const LIT: char> CharStr<LIT> {
+    const UTF8: [u8; 4] = {
+        // NOTE: `char::encode_utf8` is only `const` since Rust 1.83, which is above our MSRV.
+        let code = LIT as u32;
+        match LIT.len_utf8() {
+            1 => [code as u8, 0, 0, 0],
+            2 => [0xC0 | (code >> 6) as u8, 0x80 | (code & 0x3F) as u8, 0, 0],
+            3 => [
+                0xE0 | (code >> 12) as u8,
+                0x80 | ((code >> 6) & 0x3F) as u8,
+                0x80 | (code & 0x3F) as u8,
+                0,
+            ],
+            _ => [
+                0xF0 | (code >> 18) as u8,
+                0x80 | ((code >> 12) & 0x3F) as u8,
+                0x80 | ((code >> 6) & 0x3F) as u8,
+                0x80 | (code & 0x3F) as u8,
+            ],
+        }
+    };
+
+    const STR: &'static str = {
+        let utf8: &'static [u8; 4] = &Self::UTF8;
+        // SAFETY: The first `len_utf8()` bytes of `UTF8` are the UTF-8 encoding of `LIT`
+        unsafe {
+            let bytes = core::slice::from_raw_parts(utf8.as_ptr(), LIT.len_utf8());
+            core::str::from_utf8_unchecked(bytes)
+        }
+    };
+}
+
+impl<const LIT: char> FromLiteralChar<LIT> for &'static str {
+    const VALID_LITERAL: char = LIT;
+
+    #[inline]
+    fn into_self() -> Self {
+        CharStr::<LIT>::STR
+    }
+}
+
+impl<const LIT: char> ConstFromLiteralChar<LIT> for &'static str {
+    const VALUE: Self = CharStr::<LIT>::STR;
+}
+
+/// Build your datatype from a byte literal (`b'a'`, `b'\t'`, `b'\xff'`, ...).
+///
+/// The [macro@overloaded_literals] macro turns byte literals like
+/// ```compile_only
+/// b'x'
+/// ```
+/// into calls to
+///
+/// ```compile_only
+/// FromLiteralByte::<b'x'>::VALID_LITERAL::into_self()
+/// ```
+///
+/// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
+/// where invalid input results in a compile error.
+///
+/// The second part (`into_self()`) runs at runtime, and is where you create your actual value,
+/// knowing that the input is guaranteed to be valid.
+///
+/// ```txt
+/// FromLiteralByte::<b'x'>::VALID_LITERAL.into_self()
+/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///               compile time             ^^^^^^^^^^^
+///                                          runtime
+/// ```
+///
+/// # Example
+/// As an example, consider a delimiter for a simple CSV-like format:
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralByte};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub struct Delimiter(u8);
+///
+/// impl<const LIT: u8> FromLiteralByte<LIT> for Delimiter {
+///     const VALID_LITERAL: u8 = {
+///         assert!(LIT == b',' || LIT == b';' || LIT == b'\t', "Invalid Delimiter literal; only `,`, `;` and tab are allowed.");
+///         LIT
+///     };
+///
+///     fn into_self() -> Self {
+///         Delimiter(<Self as FromLiteralByte<LIT>>::VALID_LITERAL)
+///     }
+/// }
+/// // Usage:
+/// #[overloaded_literals]
+/// fn example() {
+///    let comma: Delimiter = b',';
+///    let tab: Delimiter = b'\t';
+///    // let boom: Delimiter = b'|'; // <- This would cause a compile error :-)
+///    assert_eq!(comma.0, 44);
+///    assert_eq!(tab.0, 9);
+/// }
+/// example()
+/// ```
+///
+/// `NonZeroU8` does not accept `b'\0'`:
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::num::NonZeroU8;
+/// #[overloaded_literals]
+/// fn example() {
+///    let byte: NonZeroU8 = b'\0';
+/// }
+/// example()
+/// ```
+pub trait FromLiteralByte<const LIT: u8> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `LIT`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: u8;
+
+    /// Turns a [VALID_LITERAL](FromLiteralByte::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    ///
+    /// You have access to [VALID_LITERAL](FromLiteralByte::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralByte<LIT>>::VALID_LITERAL;`),
+    /// and should turn it into your desired value.
+    ///
+    /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
+    /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from a byte literal inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralByte<const LIT: u8>: FromLiteralByte<LIT> {
+    /// The value built from [VALID_LITERAL](FromLiteralByte::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+impl<const LIT: u8> FromLiteralByte<LIT> for u8 {
+    const VALID_LITERAL: u8 = LIT;
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralByte<LIT>>::VALID_LITERAL
+    }
+}
+
+impl<const LIT: u8> ConstFromLiteralByte<LIT> for u8 {
+    const VALUE: Self = <Self as FromLiteralByte<LIT>>::VALID_LITERAL;
+}
+
+impl<const LIT: u8> FromLiteralByte<LIT> for NonZeroU8 {
+    const VALID_LITERAL: u8 = {
+        if LIT == 0 {
+            nonzero_byte_out_of_range()
+        }
+        LIT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralByte<LIT>>::VALUE
+    }
+}
+
+impl<const LIT: u8> ConstFromLiteralByte<LIT> for NonZeroU8 {
+    // NOTE: `Option::unwrap` is only `const` since Rust 1.83, which is above our MSRV.
+    const VALUE: Self = match NonZeroU8::new(<Self as FromLiteralByte<LIT>>::VALID_LITERAL) {
+        Some(val) => val,
+        None => nonzero_byte_out_of_range(),
+    };
+}
+
+/// Panics with `literal b'\0' is out of range for NonZeroU8 (1..=255)`.
+const fn nonzero_byte_out_of_range() -> ! {
+    let literal = ConstMessage::new().str("literal b'\\0'");
+    out_of_range(literal, "NonZeroU8", 0, u8::MAX as u128, true)
+}
+
+#[diagnostic::on_unimplemented(
+    message = "float literals cannot be used for `{Self}`",
+    label = "this float literal is used here as a `{Self}`",
+    note = "to allow this, implement `FromLiteralFloat<TFloat>` (or `FromLiteralDecimal<TDecimal>`) for `{Self}`",
+    note = "to keep this a plain float, mark it with `#[no_overload]` or give it a suffix like `f64`"
+)]
+pub trait FromLiteralFloat<TFloat: TypeFloat> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `TFloat::FLOAT` which returns a `f64`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `TFloat::FLOAT` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: f64;
+
+    /// Turns a [VALID_LITERAL](FromLiteralFloat::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    ///
+    /// You have access to [VALID_LITERAL](FromLiteralFloat::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralFloat<TFloat>>::VALID_LITERAL;`),
+    /// and should turn it into your desired value.
+    ///
+    /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
+    /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from a float literal inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralFloat<TFloat: TypeFloat>: FromLiteralFloat<TFloat> {
+    /// The value built from [VALID_LITERAL](FromLiteralFloat::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+impl<TFloat: TypeFloat> FromLiteralFloat<TFloat> for f64 {
+    const VALID_LITERAL: f64 = TFloat::FLOAT;
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralFloat<TFloat>>::VALID_LITERAL
+    }
+}
+
+impl<TFloat: TypeFloat> FromLiteralFloat<TFloat> for f32 {
+    const VALID_LITERAL: f64 = TFloat::FLOAT;
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralFloat<TFloat>>::VALID_LITERAL as f32
+    }
+}
+
+impl<TFloat: TypeFloat> ConstFromLiteralFloat<TFloat> for f64 {
+    const VALUE: Self = <Self as FromLiteralFloat<TFloat>>::VALID_LITERAL;
+}
+
+impl<TFloat: TypeFloat> ConstFromLiteralFloat<TFloat> for f32 {
+    const VALUE: Self = <Self as FromLiteralFloat<TFloat>>::VALID_LITERAL as f32;
+}
+
+/// Build your datatype from the exact decimal digits of a float literal, without binary rounding.
+///
+/// The [macro@overloaded_literals] macro passes every float literal as a [TypeDecimal] to [FromLiteralFloat].
+/// Any type implementing `FromLiteralDecimal` automatically implements [FromLiteralFloat] for these,
+/// so it can be built from float literals like `19.99` or `1.5e-3`.
+///
+/// Where [TypeFloat::FLOAT] only knows the nearest `f64` (so `0.1` is really `0.1000000000000000055511151231257827`),
+/// [TypeDecimal] gives access to the literal as written: `TDecimal::MANTISSA` is `1` and `TDecimal::EXPONENT` is `-1`.
+/// This makes it possible to build money and fixed-point types from float literals.
+///
+/// Float literals whose digits do not fit in an `i128` are only passed as a plain [TypeFloat],
+/// so these are rejected for types only implementing `FromLiteralDecimal`.
+///
+/// # Example
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralDecimal, TypeDecimal};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub struct Cents(i64);
+///
+/// impl<TDecimal: TypeDecimal> FromLiteralDecimal<TDecimal> for Cents {
+///     const VALID_LITERAL: i128 = {
+///         let mut cents = TDecimal::MANTISSA;
+///         let mut exponent = TDecimal::EXPONENT + 2;
+///         while exponent < 0 {
+///             if cents % 10 != 0 {
+///                 panic!("Cents literal has more than two decimals")
+///             }
+///             cents /= 10;
+///             exponent += 1;
+///         }
+///         while exponent > 0 {
+///             cents *= 10;
+///             exponent -= 1;
+///         }
+///         if cents < i64::MIN as i128 || cents > i64::MAX as i128 {
+///             panic!("Out of range Cents literal")
+///         }
+///         cents
+///     };
+///
+///     fn into_self() -> Self {
+///         let cents = <Self as FromLiteralDecimal<TDecimal>>::VALID_LITERAL;
+///         Cents(cents as i64)
+///     }
+/// }
+/// // Usage:
+/// #[overloaded_literals(float)]
+/// fn example() {
+///    let price: Cents = 19.99;
+///    let discount: Cents = -0.1;
+///    let total: Cents = 1.5e3;
+///    // let boom: Cents = 0.001; // <- This would cause a compile error :-)
+///    assert_eq!(price, Cents(1999));
+///    assert_eq!(discount, Cents(-10));
+///    assert_eq!(total, Cents(150000));
+/// }
+/// example()
+/// ```
+pub trait FromLiteralDecimal<TDecimal: TypeDecimal> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `TDecimal::MANTISSA` and `TDecimal::EXPONENT`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `TDecimal::MANTISSA` (possibly rescaled to the precision of your type).
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: i128;
+
+    /// Turns a [VALID_LITERAL](FromLiteralDecimal::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from the exact decimal digits of a float literal inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralDecimal<TDecimal: TypeDecimal>: FromLiteralDecimal<TDecimal> {
+    /// The value built from [VALID_LITERAL](FromLiteralDecimal::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+// NOTE: These are implemented for the concrete `Decimal` rather than for any `TDecimal: TypeDecimal`,
+// as otherwise they would overlap with other crates' `impl<TFloat: TypeFloat> FromLiteralFloat<TFloat>`.
+impl<T, const FLOAT_BITS: u64, const MANTISSA: i128, const EXPONENT: i32>
+    FromLiteralFloat<Decimal<FLOAT_BITS, MANTISSA, EXPONENT>> for T
+where
+    T: FromLiteralDecimal<Decimal<FLOAT_BITS, MANTISSA, EXPONENT>>,
+{
+    const VALID_LITERAL: f64 = {
+        // Ensure the decimal literal is validated even when only `VALID_LITERAL` is used.
+        let _ = <T as FromLiteralDecimal<Decimal<FLOAT_BITS, MANTISSA, EXPONENT>>>::VALID_LITERAL;
+        <Decimal<FLOAT_BITS, MANTISSA, EXPONENT> as TypeFloat>::FLOAT
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <T as FromLiteralDecimal<Decimal<FLOAT_BITS, MANTISSA, EXPONENT>>>::into_self()
+    }
+}
+
+impl<T, const FLOAT_BITS: u64, const MANTISSA: i128, const EXPONENT: i32>
+    ConstFromLiteralFloat<Decimal<FLOAT_BITS, MANTISSA, EXPONENT>> for T
+where
+    T: ConstFromLiteralDecimal<Decimal<FLOAT_BITS, MANTISSA, EXPONENT>>,
+{
+    const VALUE: Self =
+        <T as ConstFromLiteralDecimal<Decimal<FLOAT_BITS, MANTISSA, EXPONENT>>>::VALUE;
+}
+
+/// Build your datatype from an integer literal which is too large for a `u128` or `i128`.
+///
+/// Integer literals are normally passed to [FromLiteralUnsigned] and [FromLiteralSigned].
+/// When a literal does not fit in these, the [macro@overloaded_literals] macro turns it into a call to
+///
+/// ```compile_only
+/// FromLiteralBigInt::<TBigInt>::into_self()
+/// ```
+/// where the sign and the `u64` limbs of the literal are lifted to the type level (see [TypeBigInt]).
+///
+/// This allows big-number types (like those used in cryptography or finance) to support literals of any size.
+/// Such types will usually also implement [FromLiteralUnsigned] (and maybe [FromLiteralSigned]) for smaller literals.
+///
+/// # Example
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralBigInt, FromLiteralUnsigned, TypeBigInt};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub struct U256([u64; 4]);
+///
+/// impl<TBigInt: TypeBigInt> FromLiteralBigInt<TBigInt> for U256 {
+///     const VALID_LITERAL: &'static [u64] = {
+///         if TBigInt::NEGATIVE {
+///             panic!("Negative U256 literal")
+///         }
+///         if TBigInt::LIMBS.len() > 4 {
+///             panic!("Out of range U256 literal")
+///         }
+///         TBigInt::LIMBS
+///     };
+///
+///     fn into_self() -> Self {
+///         let limbs = <Self as FromLiteralBigInt<TBigInt>>::VALID_LITERAL;
+///         let mut res = [0; 4];
+///         res[..limbs.len()].copy_from_slice(limbs);
+///         U256(res)
+///     }
+/// }
+///
+/// impl<const LIT: u128> FromLiteralUnsigned<LIT> for U256 {
+///     const VALID_LITERAL: u128 = LIT;
+///
+///     fn into_self() -> Self {
+///         let val = <Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL;
+///         U256([val as u64, (val >> 64) as u64, 0, 0])
+///     }
+/// }
+/// // Usage:
+/// #[overloaded_literals(int)]
+/// fn example() {
+///    let max: U256 = 115792089237316195423570985008687907853269984665640564039457584007913129639935;
+///    let small: U256 = 42;
+///    // let boom: U256 = 115792089237316195423570985008687907853269984665640564039457584007913129639936; // <- This would cause a compile error :-)
+///    assert_eq!(max, U256([u64::MAX; 4]));
+///    assert_eq!(small, U256([42u64, 0u64, 0u64, 0u64]));
+/// }
+/// example()
+/// ```
+pub trait FromLiteralBigInt<TBigInt: TypeBigInt> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `TBigInt::NEGATIVE` and `TBigInt::LIMBS`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `TBigInt::LIMBS` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: &'static [u64];
+
+    /// Turns a [VALID_LITERAL](FromLiteralBigInt::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from an integer literal too large for a `u128` or `i128` inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralBigInt<TBigInt: TypeBigInt>: FromLiteralBigInt<TBigInt> {
+    /// The value built from [VALID_LITERAL](FromLiteralBigInt::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+/// Implementation to create a  `&'static CStr` from a string literal.
+/// Requires the given string literal to be:
+/// - nul terminated
+/// - not contain any nul characters in the middle
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::ffi::CStr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_not_null_terminated() {
+///    let x: &CStr = "Apple";
+///    println!("{:?}", x);
+/// }
+/// oops_not_null_terminated()
+/// ```
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::ffi::CStr;
+///
+/// #[overloaded_literals]
+/// pub fn oops_nul_in_the_middle() {
+///    let x: &CStr = "Ba\0nana\0";
+///    println!("{:?}", x);
+/// }
+/// oops_nul_in_the_middle()
+/// ```
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::ffi::CStr;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let x: &CStr = "Dragon fruit\0";
+///    println!("{:?}", x);
+/// }
+/// correct()
+/// ```
+impl<TStr: TypeStr> FromLiteralStr<TStr> for &'static CStr {
+    const VALID_LITERAL: &'static str = {
+        assert_valid_cstr(TStr::STR.as_bytes());
+        TStr::STR
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        let bytes = <Self as FromLiteralStr<TStr>>::VALID_LITERAL.as_bytes();
+        // SAFETY: VALID_LITERAL is checked at compile time
+        unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+    }
+}
+
+impl<TStr: TypeStr> ConstFromLiteralStr<TStr> for &'static CStr {
+    const VALUE: Self = {
+        let bytes = <Self as FromLiteralStr<TStr>>::VALID_LITERAL.as_bytes();
+        // SAFETY: VALID_LITERAL is checked at compile time
+        unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+    };
+}
+
+/// Compile-time check that `bytes` is nul-terminated and contains no other nul bytes.
+const fn assert_valid_cstr(bytes: &[u8]) {
+    assert!(
+        !bytes.is_empty() && bytes[bytes.len() - 1] == 0,
+        "nul-terminator missing for CStr"
+    );
+    let mut i = 0;
+    while i < bytes.len() - 1 {
+        assert!(bytes[i] != 0, "CStr cannot have nul bytes in the middle");
+        i += 1;
+    }
+}
+
+/// Build your datatype from a byte string literal (`b"..."`).
+///
+/// The [macro@overloaded_literals] macro turns byte string literals like
+/// ```compile_only
+/// b"\x89PNG"
+/// ```
+/// into calls to
+///
+/// ```compile_only
+/// FromLiteralByteStr::<b"\x89PNG">::VALID_LITERAL::into_self()
+/// ```
+/// _NOTE: Like for [FromLiteralStr], the real desugaring is slightly more involved, see [TypeBytes] if you are curious._
+///
+/// Contrary to string literals, byte string literals do not need to be valid UTF-8.
+///
+/// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
+/// where invalid input results in a compile error.
+///
+/// The second part (`into_self()`) runs at runtime, and is where you create your actual value,
+/// knowing that the input is guaranteed to be valid.
+///
+/// # Example
+/// As an example, consider a file signature that must be exactly 4 bytes long and start with a non-ASCII byte:
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralByteStr, TypeBytes};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub struct Magic([u8; 4]);
+///
+/// impl<TBytes: TypeBytes> FromLiteralByteStr<TBytes> for Magic {
+///     const VALID_LITERAL: &'static [u8] = {
+///         let bytes = TBytes::BYTES;
+///         assert!(bytes.len() == 4, "Magic literal must be exactly 4 bytes long");
+///         assert!(!bytes[0].is_ascii(), "Magic literal must start with a non-ASCII byte");
+///         bytes
+///     };
+///
+///     fn into_self() -> Self {
+///         let bytes = <Self as FromLiteralByteStr<TBytes>>::VALID_LITERAL;
+///         Magic([bytes[0], bytes[1], bytes[2], bytes[3]])
+///     }
+/// }
+/// // Usage:
+/// #[overloaded_literals]
+/// fn example() {
+///    let png: Magic = b"\x89PNG";
+///    let header: &[u8; 3] = b"GET";
+///    let raw: &[u8] = b"\xff\x00";
+///    // let boom: Magic = b"PNG"; // <- This would cause a compile error :-)
+///    assert_eq!(png.0.len(), 4);
+///    assert_eq!(png.0.first(), Some(&0x89));
+///    assert_eq!(header.len(), 3);
+///    assert_eq!(raw.len(), 2);
+/// }
+/// example()
+/// ```
+///
+/// The length of the literal is checked when building a fixed-size array reference:
+///
+/// ```compile_fail
+/// # use overloaded_literals::overloaded_literals;
+/// #[overloaded_literals]
+/// fn example() {
+///    let header: &[u8; 4] = b"GET";
+/// }
+/// example()
+/// ```
+pub trait FromLiteralByteStr<TBytes: TypeBytes> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `TBytes::BYTES` which returns a `&'static [u8]`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `TBytes::BYTES` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: &'static [u8];
+
+    /// Turns a [VALID_LITERAL](FromLiteralByteStr::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    ///
+    /// You have access to [VALID_LITERAL](FromLiteralByteStr::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralByteStr<TBytes>>::VALID_LITERAL;`),
+    /// and should turn it into your desired value.
+    ///
+    /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
+    /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from a byte string literal inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralByteStr<TBytes: TypeBytes>: FromLiteralByteStr<TBytes> {
+    /// The value built from [VALID_LITERAL](FromLiteralByteStr::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+impl<TBytes: TypeBytes> FromLiteralByteStr<TBytes> for &'static [u8] {
+    const VALID_LITERAL: &'static [u8] = TBytes::BYTES;
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as FromLiteralByteStr<TBytes>>::VALID_LITERAL
+    }
+}
+
+impl<TBytes: TypeBytes> ConstFromLiteralByteStr<TBytes> for &'static [u8] {
+    const VALUE: Self = <Self as FromLiteralByteStr<TBytes>>::VALID_LITERAL;
+}
+
+impl<TBytes: TypeBytes, const N: usize> FromLiteralByteStr<TBytes> for &'static [u8; N] {
+    const VALID_LITERAL: &'static [u8] = {
+        assert!(
+            TBytes::BYTES.len() == N,
+            "Byte string literal length does not match the array length"
+        );
+        TBytes::BYTES
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralByteStr<TBytes>>::VALUE
+    }
+}
+
+impl<TBytes: TypeBytes, const N: usize> ConstFromLiteralByteStr<TBytes> for &'static [u8; N] {
+    const VALUE: Self = {
+        let bytes = <Self as FromLiteralByteStr<TBytes>>::VALID_LITERAL;
+        // SAFETY: The length of VALID_LITERAL is checked to be N at compile time
+        unsafe { &*(bytes.as_ptr() as *const [u8; N]) }
+    };
+}
+
+/// Implementation to create a `&'static CStr` from a byte string literal.
+/// Just like [the implementation for string literals](#impl-FromLiteralStr<TStr>-for-%26%27static%20CStr),
+/// requires the literal to be nul terminated and not contain any nul bytes in the middle,
+/// but allows the other bytes to be non-UTF-8.
+///
+/// ```rust
+/// # use overloaded_literals::overloaded_literals;
+/// # use core::ffi::CStr;
+///
+/// #[overloaded_literals]
+/// pub fn correct() {
+///    let x: &CStr = b"Caf\xe9\0";
+///    assert_eq!(x.to_bytes().len(), 4);
+/// }
+/// correct()
+/// ```
+impl<TBytes: TypeBytes> FromLiteralByteStr<TBytes> for &'static CStr {
+    const VALID_LITERAL: &'static [u8] = {
+        assert_valid_cstr(TBytes::BYTES);
+        TBytes::BYTES
+    };
+
+    #[inline]
+    fn into_self() -> Self {
+        <Self as ConstFromLiteralByteStr<TBytes>>::VALUE
     }
 }
 
-/// Build your datatype from a boolean literal (`false` or `true`).
+impl<TBytes: TypeBytes> ConstFromLiteralByteStr<TBytes> for &'static CStr {
+    const VALUE: Self = {
+        let bytes = <Self as FromLiteralByteStr<TBytes>>::VALID_LITERAL;
+        // SAFETY: VALID_LITERAL is checked at compile time
+        unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+    };
+}
+
+/// Build your datatype from a C string literal (`c"..."`, available since Rust 1.77).
 ///
-/// The [macro@overloaded_literals] macro turns boolean literals like
+/// The [macro@overloaded_literals] macro turns C string literals like
 /// ```compile_only
-/// true
+/// c"hello"
 /// ```
 /// into calls to
 ///
 /// ```compile_only
-/// FromLiteralBool::<true>::VALID_LITERAL::into_self()
+/// FromLiteralCStr::<c"hello">::VALID_LITERAL::into_self()
 /// ```
+/// _NOTE: Like for [FromLiteralStr], the real desugaring is slightly more involved, see [TypeCStr] if you are curious._
 ///
+/// Contrary to building a `&'static CStr` from a normal string literal,
+/// the compiler already ensures that a C string literal has no nul bytes in the middle,
+/// and adds the nul terminator for you.
 ///
 /// The first part (`VALID_LITERAL`) runs at compile-time, allowing you to perform input checks,
 /// where invalid input results in a compile error.
@@ -415,29 +2236,60 @@ impl<T: FromLiteralSigned<LIT>, const LIT: i128> FromLiteralSigned<LIT> for Wrap
 /// The second part (`into_self()`) runs at runtime, and is where you create your actual value,
 /// knowing that the input is guaranteed to be valid.
 ///
-/// ```txt
-/// FromLiteralBool::<true>::VALID_LITERAL.into_self()
-/// ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-///               compile time             ^^^^^^^^^^^
-///                                          runtime
-/// ```
+/// # Example
+/// As an example, consider the name of a symbol to look up in a shared library, which has to be a valid C identifier.
+/// Inside `VALID_LITERAL`, `TCStr::BYTES` contains the bytes including the nul terminator:
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralCStr, TypeBytes, TypeCStr};
+/// use core::ffi::CStr;
 ///
-pub trait FromLiteralBool<const LIT: bool> {
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub struct SymbolName(&'static CStr);
+///
+/// impl<TCStr: TypeCStr> FromLiteralCStr<TCStr> for SymbolName {
+///     const VALID_LITERAL: &'static CStr = {
+///         let bytes = TCStr::BYTES;
+///         assert!(bytes.len() > 1, "SymbolName literal cannot be empty");
+///         assert!(!bytes[0].is_ascii_digit(), "SymbolName literal cannot start with a digit");
+///         let mut i = 0;
+///         while i < bytes.len() - 1 {
+///             assert!(bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_', "SymbolName literal can only contain ASCII letters, digits and underscores");
+///             i += 1;
+///         }
+///         TCStr::CSTR
+///     };
+///
+///     fn into_self() -> Self {
+///         SymbolName(<Self as FromLiteralCStr<TCStr>>::VALID_LITERAL)
+///     }
+/// }
+/// // Usage:
+/// #[overloaded_literals]
+/// fn example() {
+///    let symbol: SymbolName = c"sqlite3_open_v2";
+///    let raw: &CStr = c"hello";
+///    // let boom: SymbolName = c"not-an-identifier"; // <- This would cause a compile error :-)
+///    assert_eq!(symbol.0.to_bytes().len(), 15);
+///    assert_eq!(raw.to_bytes().len(), 5);
+/// }
+/// example()
+/// ```
+pub trait FromLiteralCStr<TCStr: TypeCStr> {
     /// The definition of `VALID_LITERAL` is evaluated at compile-time.
     ///
-    /// Inside this definition you have access to `LIT`.
+    /// Inside this definition you have access to `TCStr::CSTR` which returns a `&'static CStr`.
     ///
     /// An implementation of `VALID_LITERAL` should perform input checking:
-    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is valid, return `TCStr::CSTR` unchanged.
     /// - If the input is invalid, [panic](core::panic!).
     ///   Because this is evaluated at compile-time, this results in a compile error.
-    const VALID_LITERAL: bool;
+    const VALID_LITERAL: &'static CStr;
 
-    /// Turns a [VALID_LITERAL](FromLiteralBool::VALID_LITERAL) into the actual runtime value.
+    /// Turns a [VALID_LITERAL](FromLiteralCStr::VALID_LITERAL) into the actual runtime value.
     ///
     /// This part runs at runtime.
     ///
-    /// You have access to [VALID_LITERAL](FromLiteralBool::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralBool<LIT>>::VALID_LITERAL;`),
+    /// You have access to [VALID_LITERAL](FromLiteralCStr::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralCStr<TCStr>>::VALID_LITERAL;`),
     /// and should turn it into your desired value.
     ///
     /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
@@ -445,19 +2297,137 @@ pub trait FromLiteralBool<const LIT: bool> {
     fn into_self() -> Self;
 }
 
-impl<const LIT: bool> FromLiteralBool<LIT> for bool {
-    const VALID_LITERAL: bool = LIT;
+/// Build your datatype from a C string literal inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralCStr<TCStr: TypeCStr>: FromLiteralCStr<TCStr> {
+    /// The value built from [VALID_LITERAL](FromLiteralCStr::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+impl<TCStr: TypeCStr> FromLiteralCStr<TCStr> for &'static CStr {
+    const VALID_LITERAL: &'static CStr = TCStr::CSTR;
 
     #[inline]
     fn into_self() -> Self {
-        <Self as FromLiteralBool<LIT>>::VALID_LITERAL
+        <Self as FromLiteralCStr<TCStr>>::VALID_LITERAL
     }
 }
 
-pub trait FromLiteralFloat<TFloat: TypeFloat> {
+impl<TCStr: TypeCStr> ConstFromLiteralCStr<TCStr> for &'static CStr {
+    const VALUE: Self = <Self as FromLiteralCStr<TCStr>>::VALID_LITERAL;
+}
+
+/// Build your datatype from an unsigned integer literal with a custom suffix, like `30ms` or `4KiB`.
+///
+/// Similar to user-defined literals in C++, the [macro@overloaded_literals] macro turns integer literals with a suffix like
+/// ```compile_only
+/// 30ms
+/// ```
+/// into calls to
+///
+/// ```compile_only
+/// FromLiteralSuffixedUnsigned::<"ms", 30>::VALID_LITERAL::into_self()
+/// ```
+/// where the suffix is lifted to the type level just like string literals are (see [TypeStr]).
+///
+/// The built-in suffixes (like `10usize` or `1.5f32`) keep their normal meaning.
+/// Custom suffixes on other kinds of literals (like `b"x"foo` or `'x'foo`) result in a compile error.
+///
+/// Usually, you'll implement this trait for any `Suffix: TypeStr`,
+/// checking in `VALID_LITERAL` that `Suffix::STR` is one of the suffixes your type supports.
+/// Variants of this trait exist for negative integer literals ([FromLiteralSuffixedSigned]),
+/// float literals ([FromLiteralSuffixedFloat]) and string literals ([FromLiteralSuffixedStr]).
+///
+/// Implementations are included for [Duration], supporting the suffixes `ns`, `us`, `ms`, `s`, `min` and `h`.
+///
+/// # Example
+/// ```rust
+/// use overloaded_literals::{overloaded_literals, FromLiteralSuffixedUnsigned, TypeStr};
+/// use core::time::Duration;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub struct ByteSize(u64);
+///
+/// const fn unit_size(suffix: &str) -> u128 {
+///     match suffix.as_bytes() {
+///         b"B" => 1,
+///         b"KiB" => 1 << 10,
+///         b"MiB" => 1 << 20,
+///         b"GiB" => 1 << 30,
+///         _ => panic!("Invalid ByteSize literal suffix; only `B`, `KiB`, `MiB` and `GiB` are allowed."),
+///     }
+/// }
+///
+/// impl<Suffix: TypeStr, const LIT: u128> FromLiteralSuffixedUnsigned<Suffix, LIT> for ByteSize {
+///     const VALID_LITERAL: u128 = {
+///         let size = LIT * unit_size(Suffix::STR);
+///         assert!(size <= u64::MAX as u128, "Out of range ByteSize literal");
+///         LIT
+///     };
+///
+///     fn into_self() -> Self {
+///         let val = <Self as FromLiteralSuffixedUnsigned<Suffix, LIT>>::VALID_LITERAL;
+///         ByteSize((val * unit_size(Suffix::STR)) as u64)
+///     }
+/// }
+/// // Usage:
+/// #[overloaded_literals]
+/// fn example() {
+///    let size: ByteSize = 4KiB;
+///    let timeout: Duration = 30ms;
+///    let index = 10usize; // <- A normal `usize`
+///    // let boom: ByteSize = 4KB; // <- This would cause a compile error :-)
+///    assert_eq!(size, ByteSize(4096));
+///    assert_eq!(timeout.as_millis(), 30);
+///    assert_eq!(index, 10usize);
+/// }
+/// example()
+/// ```
+pub trait FromLiteralSuffixedUnsigned<Suffix: TypeStr, const LIT: u128> {
     /// The definition of `VALID_LITERAL` is evaluated at compile-time.
     ///
-    /// Inside this definition you have access to `TFloat::FLOAT` which returns a `f64`.
+    /// Inside this definition you have access to `LIT` and to `Suffix::STR`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: u128;
+
+    /// Turns a [VALID_LITERAL](FromLiteralSuffixedUnsigned::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from a negative integer literal with a custom suffix, like `-30ms`.
+///
+/// See [FromLiteralSuffixedUnsigned] for details.
+pub trait FromLiteralSuffixedSigned<Suffix: TypeStr, const LIT: i128> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `LIT` and to `Suffix::STR`.
+    ///
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `LIT` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: i128;
+
+    /// Turns a [VALID_LITERAL](FromLiteralSuffixedSigned::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from a float literal with a custom suffix, like `1.5s`.
+///
+/// See [FromLiteralSuffixedUnsigned] for details.
+pub trait FromLiteralSuffixedFloat<Suffix: TypeStr, TFloat: TypeFloat> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `TFloat::FLOAT` and to `Suffix::STR`.
     ///
     /// An implementation of `VALID_LITERAL` should perform input checking:
     /// - If the input is valid, return `TFloat::FLOAT` unchanged.
@@ -465,96 +2435,255 @@ pub trait FromLiteralFloat<TFloat: TypeFloat> {
     ///   Because this is evaluated at compile-time, this results in a compile error.
     const VALID_LITERAL: f64;
 
-    /// Turns a [VALID_LITERAL](FromLiteralFloat::VALID_LITERAL) into the actual runtime value.
+    /// Turns a [VALID_LITERAL](FromLiteralSuffixedFloat::VALID_LITERAL) into the actual runtime value.
     ///
     /// This part runs at runtime.
+    fn into_self() -> Self;
+}
+
+/// Build your datatype from a string literal with a custom suffix, like `"a.b"path`.
+///
+/// See [FromLiteralSuffixedUnsigned] for details.
+pub trait FromLiteralSuffixedStr<Suffix: TypeStr, TStr: TypeStr> {
+    /// The definition of `VALID_LITERAL` is evaluated at compile-time.
     ///
-    /// You have access to [VALID_LITERAL](FromLiteralFloat::VALID_LITERAL) (using the syntax `let val = <Self as FromLiteralFloat<TFloat>>::VALID_LITERAL;`),
-    /// and should turn it into your desired value.
+    /// Inside this definition you have access to `TStr::STR` and to `Suffix::STR`.
     ///
-    /// If you want, you can use an unsafe 'unchecked' constructor, if one exists, since you have done any validation already.
-    /// (But even if using a normal constructor, in all likelyhood the compiler is smart enough to remove the duplicate checks since the input is a literal value.)
+    /// An implementation of `VALID_LITERAL` should perform input checking:
+    /// - If the input is valid, return `TStr::STR` unchanged.
+    /// - If the input is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LITERAL: &'static str;
+
+    /// Turns a [VALID_LITERAL](FromLiteralSuffixedStr::VALID_LITERAL) into the actual runtime value.
+    ///
+    /// This part runs at runtime.
     fn into_self() -> Self;
 }
 
-impl<TFloat: TypeFloat> FromLiteralFloat<TFloat> for f64 {
-    const VALID_LITERAL: f64 = TFloat::FLOAT;
+/// Build your datatype from an unsigned integer literal with a custom suffix inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralSuffixedUnsigned<Suffix: TypeStr, const LIT: u128>:
+    FromLiteralSuffixedUnsigned<Suffix, LIT>
+{
+    /// The value built from [VALID_LITERAL](FromLiteralSuffixedUnsigned::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+/// Build your datatype from a negative integer literal with a custom suffix inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralSuffixedSigned<Suffix: TypeStr, const LIT: i128>:
+    FromLiteralSuffixedSigned<Suffix, LIT>
+{
+    /// The value built from [VALID_LITERAL](FromLiteralSuffixedSigned::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+/// Build your datatype from a float literal with a custom suffix inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralSuffixedFloat<Suffix: TypeStr, TFloat: TypeFloat>:
+    FromLiteralSuffixedFloat<Suffix, TFloat>
+{
+    /// The value built from [VALID_LITERAL](FromLiteralSuffixedFloat::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+/// Build your datatype from a string literal with a custom suffix inside const contexts.
+///
+/// See [ConstFromLiteralUnsigned] for details.
+pub trait ConstFromLiteralSuffixedStr<Suffix: TypeStr, TStr: TypeStr>:
+    FromLiteralSuffixedStr<Suffix, TStr>
+{
+    /// The value built from [VALID_LITERAL](FromLiteralSuffixedStr::VALID_LITERAL), evaluated at compile-time.
+    const VALUE: Self;
+}
+
+/// The number of nanoseconds in a single unit of a [Duration] literal suffix, like the `ms` in `30ms`.
+const fn duration_unit_nanos(suffix: &str) -> u128 {
+    match suffix.as_bytes() {
+        b"ns" => 1,
+        b"us" => 1_000,
+        b"ms" => 1_000_000,
+        b"s" => 1_000_000_000,
+        b"min" => 60 * 1_000_000_000,
+        b"h" => 60 * 60 * 1_000_000_000,
+        _ => panic!("Unsupported Duration literal suffix; expected one of `ns`, `us`, `ms`, `s`, `min` or `h`"),
+    }
+}
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Implementation to create a [Duration] from an integer literal with a unit suffix, like `30ms`.
+impl<Suffix: TypeStr, const LIT: u128> FromLiteralSuffixedUnsigned<Suffix, LIT> for Duration {
+    const VALID_LITERAL: u128 = {
+        let nanos = LIT.checked_mul(duration_unit_nanos(Suffix::STR));
+        assert!(
+            matches!(nanos, Some(nanos) if nanos / NANOS_PER_SEC <= u64::MAX as u128),
+            "Out of range Duration literal"
+        );
+        LIT
+    };
 
     #[inline]
     fn into_self() -> Self {
-        <Self as FromLiteralFloat<TFloat>>::VALID_LITERAL
+        <Self as ConstFromLiteralSuffixedUnsigned<Suffix, LIT>>::VALUE
     }
 }
 
-impl<TFloat: TypeFloat> FromLiteralFloat<TFloat> for f32 {
-    const VALID_LITERAL: f64 = TFloat::FLOAT;
+impl<Suffix: TypeStr, const LIT: u128> ConstFromLiteralSuffixedUnsigned<Suffix, LIT> for Duration {
+    const VALUE: Self = {
+        let val = <Self as FromLiteralSuffixedUnsigned<Suffix, LIT>>::VALID_LITERAL;
+        let nanos = val * duration_unit_nanos(Suffix::STR);
+        Duration::new(
+            (nanos / NANOS_PER_SEC) as u64,
+            (nanos % NANOS_PER_SEC) as u32,
+        )
+    };
+}
+
+/// Implementation to create a [Duration] from a float literal with a unit suffix, like `1.5s`.
+///
+/// The result is rounded down to whole nanoseconds.
+impl<Suffix: TypeStr, TFloat: TypeFloat> FromLiteralSuffixedFloat<Suffix, TFloat> for Duration {
+    const VALID_LITERAL: f64 = {
+        let secs = TFloat::FLOAT * duration_unit_nanos(Suffix::STR) as f64 / NANOS_PER_SEC as f64;
+        assert!(
+            secs >= 0.0 && secs < u64::MAX as f64,
+            "Out of range Duration literal"
+        );
+        TFloat::FLOAT
+    };
 
     #[inline]
     fn into_self() -> Self {
-        <Self as FromLiteralFloat<TFloat>>::VALID_LITERAL as f32
+        <Self as ConstFromLiteralSuffixedFloat<Suffix, TFloat>>::VALUE
     }
 }
 
-/// Implementation to create a  `&'static CStr` from a string literal.
-/// Requires the given string literal to be:
-/// - nul terminated
-/// - not contain any nul characters in the middle
+impl<Suffix: TypeStr, TFloat: TypeFloat> ConstFromLiteralSuffixedFloat<Suffix, TFloat>
+    for Duration
+{
+    const VALUE: Self = {
+        let val = <Self as FromLiteralSuffixedFloat<Suffix, TFloat>>::VALID_LITERAL;
+        let nanos = val * duration_unit_nanos(Suffix::STR) as f64;
+        let secs = (nanos / NANOS_PER_SEC as f64) as u64;
+        let subsec_nanos = (nanos - secs as f64 * NANOS_PER_SEC as f64) as u32;
+        Duration::new(secs, subsec_nanos)
+    };
+}
+
+/// Build your datatype from an array literal, like `[1, 2, 3]`.
 ///
-/// ```compile_fail
-/// # use overloaded_literals::overloaded_literals;
-/// # use core::ffi::CStr;
+/// Similar to Haskell's `OverloadedLists`, when passing the `arrays` argument the [macro@overloaded_literals] macro turns array expressions like
+/// ```compile_only
+/// [1, 2, 3]
+/// ```
+/// into calls to
 ///
-/// #[overloaded_literals]
-/// pub fn oops_not_null_terminated() {
-///    let x: &CStr = "Apple";
-///    println!("{:?}", x);
-/// }
-/// oops_not_null_terminated()
+/// ```compile_only
+/// FromLiteralArray::<_, 3>::into_self([1, 2, 3])
 /// ```
+/// where the elements themselves are overloaded as usual.
 ///
-/// ```compile_fail
-/// # use overloaded_literals::overloaded_literals;
-/// # use core::ffi::CStr;
+/// Unlike the other `FromLiteral*` traits, the elements are not known at compile-time,
+/// so only the number of elements `N` can be validated.
+/// This makes it useful for types like fixed-capacity vectors, non-empty lists or bounded-length lists.
 ///
-/// #[overloaded_literals]
-/// pub fn oops_nul_in_the_middle() {
-///    let x: &CStr = "Ba\0nana\0";
-///    println!("{:?}", x);
-/// }
-/// oops_nul_in_the_middle()
-/// ```
+/// An implementation is included for `[T; N]` itself, so plain arrays keep working.
 ///
+/// # Example
 /// ```rust
-/// # use overloaded_literals::overloaded_literals;
-/// # use core::ffi::CStr;
+/// use overloaded_literals::{overloaded_literals, FromLiteralArray};
 ///
-/// #[overloaded_literals]
-/// pub fn correct() {
-///    let x: &CStr = "Dragon fruit\0";
-///    println!("{:?}", x);
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub struct Bounded<const CAP: usize> {
+///     items: [u8; CAP],
+///     len: usize,
 /// }
-/// correct()
+///
+/// impl<const CAP: usize, const N: usize> FromLiteralArray<u8, N> for Bounded<CAP> {
+///     const VALID_LENGTH: usize = {
+///         if N == 0 {
+///             panic!("Empty Bounded array literal")
+///         }
+///         if N > CAP {
+///             panic!("Bounded array literal has more elements than its capacity")
+///         }
+///         N
+///     };
+///
+///     fn into_self(elements: [u8; N]) -> Self {
+///         let len = <Self as FromLiteralArray<u8, N>>::VALID_LENGTH;
+///         let mut items = [0; CAP];
+///         items[..len].copy_from_slice(&elements);
+///         Bounded { items, len }
+///     }
+/// }
+/// // Usage:
+/// #[overloaded_literals(arrays)]
+/// fn example() {
+///    let list: Bounded<4> = [1, 2, 3];
+///    // let boom: Bounded<2> = [1, 2, 3]; // <- This would cause a compile error :-)
+///    assert_eq!(list.len, 3usize);
+///    let expected: [u8; 4] = [1, 2, 3, 0]; // <- Plain arrays work as well
+///    assert_eq!(list.items, expected);
+/// }
+/// example()
 /// ```
-impl<TStr: TypeStr> FromLiteralStr<TStr> for &'static CStr {
-    const VALID_LITERAL: &'static str = {
-        let bytes = TStr::STR.as_bytes();
-        let mut i = 0;
-        while i < bytes.len() - 1 {
-            assert!(bytes[i] != 0, "CStr cannot have nul bytes in the middle");
-            i += 1;
-        }
-        assert!(
-            bytes[bytes.len() - 1] == 0,
-            "nul-terminator missing for CStr"
-        );
-        TStr::STR
-    };
+///
+/// ```compile_fail
+/// # use overloaded_literals::{overloaded_literals, FromLiteralArray};
+/// # pub struct Bounded<const CAP: usize>([u8; CAP]);
+/// # impl<const CAP: usize, const N: usize> FromLiteralArray<u8, N> for Bounded<CAP> {
+/// #     const VALID_LENGTH: usize = {
+/// #         if N > CAP {
+/// #             panic!("Bounded array literal has more elements than its capacity")
+/// #         }
+/// #         N
+/// #     };
+/// #     fn into_self(elements: [u8; N]) -> Self {
+/// #         let len = <Self as FromLiteralArray<u8, N>>::VALID_LENGTH;
+/// #         let mut items = [0; CAP];
+/// #         items[..len].copy_from_slice(&elements);
+/// #         Bounded(items)
+/// #     }
+/// # }
+/// #[overloaded_literals(arrays)]
+/// fn example() {
+///    let boom: Bounded<2> = [1, 2, 3];
+/// }
+/// example()
+/// ```
+pub trait FromLiteralArray<Element, const N: usize> {
+    /// The definition of `VALID_LENGTH` is evaluated at compile-time.
+    ///
+    /// Inside this definition you have access to `N`, the number of elements in the array literal.
+    ///
+    /// An implementation of `VALID_LENGTH` should perform input checking:
+    /// - If the length is valid, return `N` unchanged.
+    /// - If the length is invalid, [panic](core::panic!).
+    ///   Because this is evaluated at compile-time, this results in a compile error.
+    const VALID_LENGTH: usize;
+
+    /// Turns the elements of the array literal into the actual runtime value.
+    ///
+    /// This part runs at runtime.
+    ///
+    /// Make sure to refer to [VALID_LENGTH](FromLiteralArray::VALID_LENGTH) here (using the syntax `let len = <Self as FromLiteralArray<Element, N>>::VALID_LENGTH;`),
+    /// as otherwise it is never evaluated and the length is not checked.
+    fn into_self(elements: [Element; N]) -> Self;
+}
+
+/// Implementation for plain arrays, which accepts every length.
+impl<T, const N: usize> FromLiteralArray<T, N> for [T; N] {
+    const VALID_LENGTH: usize = N;
 
     #[inline]
-    fn into_self() -> Self {
-        let bytes = <Self as FromLiteralStr<TStr>>::VALID_LITERAL.as_bytes();
-        // SAFETY: VALID_LITERAL is checked at compile time
-        unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+    fn into_self(elements: [T; N]) -> Self {
+        elements
     }
 }
 
@@ -638,6 +2767,16 @@ mod tests {
         // let x: i8 = FromLiteralSigned::<-200>::into_self();
     }
 
+    #[test]
+    fn unsigned_literal_for_signed_type() {
+        let x: i8 = FromLiteralUnsigned::<0>::into_self();
+        assert_eq!(x, 0);
+        let x: i8 = FromLiteralUnsigned::<127>::into_self();
+        assert_eq!(x, 127);
+        let x: isize = FromLiteralUnsigned::<42>::into_self();
+        assert_eq!(x, 42);
+    }
+
     // #[test]
     // fn compile_time_error_on_invalid_inputs() {
     //     let y: u8 = FromLiteralSigned::<1024>::into_self();
@@ -651,14 +2790,89 @@ mod tests {
     // }
     #[test]
     fn literal_str() {
-        use tlist::TList;
-        use type_str::Byte;
-        // [103, 114, 101, 101, 116, 105, 110, 103, 0]
-        // let y: Greeting = FromLiteralStr::<TList![Char<103>, Char<114>, Char<101>, Char<101>, Char<116>, Char<105>, Char<110>, Char<103>]>::into_self();
+        use type_bytes::{Chunk, Concat};
+        let y: &'static str = FromLiteralStr::<Chunk<5, 0x6f6c6c6568>>::into_self();
+        assert_eq!(y, "hello");
         let y: &'static str = FromLiteralStr::<
-            TList![Byte<104>, Byte<101>, Byte<108>, Byte<108>, Byte<111>],
+            Concat<8, Chunk<5, 0x6f6c6c6568>, Concat<3, Chunk<1, 0x2c>, Chunk<2, 0x6968>>>,
         >::into_self();
-        println!("greeting: {y:?}");
+        assert_eq!(y, "hello,hi");
+    }
+
+    #[test]
+    #[overloaded_literals]
+    fn radix_bit_pattern() {
+        let v: i8 = 0xFF;
+        assert_eq!(v, -1);
+        let v: i16 = 0o177777;
+        assert_eq!(v, -1);
+        let v: i32 = 0b0111_1111;
+        assert_eq!(v, 127);
+        let v: Wrapping<i64> = 0xFFFF_FFFF_FFFF_FFFE;
+        assert_eq!(v.0, -2);
+        let v: u8 = 0xFF;
+        assert_eq!(v, 255);
+        const V: i8 = 0x80;
+        assert_eq!(V, -128);
+        let v: NonZeroI8 = 0xFF;
+        assert_eq!(v.get(), -1);
+        const W: NonZeroI16 = 0x8000;
+        assert_eq!(W.get(), i16::MIN);
+    }
+
+    #[test]
+    fn ol_const_context() {
+        static RETRIES: NonZeroU8 = ol!(const; 5);
+        const OFFSET: NonZeroI8 = ol!(const; -3);
+        const NAME: &str = ol!(const; "retry");
+        static MASK: i8 = ol!(crate = crate; const; 0xFF);
+        assert_eq!(RETRIES.get(), 5);
+        assert_eq!(OFFSET.get(), -3);
+        assert_eq!(NAME, "retry");
+        assert_eq!(MASK, -1);
+    }
+
+    #[test]
+    #[overloaded_literals]
+    fn radix_for_unsigned_impl() {
+        // Types implementing only `FromLiteralUnsigned` accept any radix
+        #[derive(Debug, PartialEq, Eq)]
+        struct Flags(u8);
+
+        impl<const LIT: u128> FromLiteralUnsigned<LIT> for Flags {
+            const VALID_LITERAL: u128 = <u8 as FromLiteralUnsigned<LIT>>::VALID_LITERAL;
+
+            fn into_self() -> Self {
+                Flags(<Self as FromLiteralUnsigned<LIT>>::VALID_LITERAL as u8)
+            }
+        }
+
+        let v: Flags = 0xFF;
+        assert_eq!(v, Flags(255u8));
+        let v: Flags = 0o7;
+        assert_eq!(v, Flags(7u8));
+        let v: Flags = 0b1;
+        assert_eq!(v, Flags(1u8));
+    }
+
+    #[test]
+    fn literal_big_int() {
+        use type_bigint::{BigInt, Concat, Limb};
+        type Big = BigInt<true, Concat<3, Limb<0>, Concat<2, Limb<0>, Limb<1>>>>;
+        let negative = <Big as TypeBigInt>::NEGATIVE;
+        assert!(negative);
+        assert_eq!(<Big as TypeBigInt>::LIMBS, &[0, 0, 1]);
+    }
+
+    #[test]
+    fn literal_char() {
+        let a: &'static str = FromLiteralChar::<'a'>::into_self();
+        let e: &'static str = FromLiteralChar::<'é'>::into_self();
+        let euro: &'static str = FromLiteralChar::<'€'>::into_self();
+        let crab: &'static str = FromLiteralChar::<'🦀'>::into_self();
+        assert_eq!((a, e, euro, crab), ("a", "é", "€", "🦀"));
+        let code: u32 = FromLiteralChar::<'€'>::into_self();
+        assert_eq!(code, 0x20AC);
     }
 
     // #[test]
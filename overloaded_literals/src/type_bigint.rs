@@ -0,0 +1,91 @@
+//! Lifting of integer literals which do not fit in a `u128` or `i128` to the type level,
+//! to allow usage of arbitrarily large integers in generic const contexts.
+//!
+//! Items in this module need to be public as the types and structs contained within
+//! are built by the `overloaded_literals!` macro.
+//!
+//! However, **consider the contents of this module an implementation detail,
+//! and do not depend on these details directly in your code. They are subject to change**.
+//!
+//! The only API which can be considered public and is guaranteed, is [TypeBigInt::NEGATIVE] and [TypeBigInt::LIMBS].
+use crate::sealed::Sealed;
+use core::marker::PhantomData;
+
+/// Struct to lift a single u64 limb to the type level.
+///
+/// Implementation detail of [TypeBigInt].
+pub struct Limb<const VAL: u64>;
+
+/// Struct to lift the concatenation of two lists of limbs to the type level.
+///
+/// `LEN` is the total number of limbs of `Left` and `Right`
+/// (which is passed separately so it can be used as the size of an array).
+///
+/// Implementation detail of [TypeBigInt].
+pub struct Concat<const LEN: usize, Left, Right>(PhantomData<(Left, Right)>);
+
+impl<const VAL: u64> Sealed for Limb<VAL> {}
+impl<const LEN: usize, Left, Right> Sealed for Concat<LEN, Left, Right> {}
+
+/// Implementation detail of [TypeBigInt]: A balanced tree of [Limb]s,
+/// built in the same way as the byte strings of [TypeBytes](crate::TypeBytes).
+pub trait TypeLimbs: Sealed {
+    #[doc(hidden)]
+    const LIMBS: &'static [u64];
+}
+
+impl<const VAL: u64> TypeLimbs for Limb<VAL> {
+    const LIMBS: &'static [u64] = &[VAL];
+}
+
+impl<const LEN: usize, Left: TypeLimbs, Right: TypeLimbs> TypeLimbs for Concat<LEN, Left, Right> {
+    const LIMBS: &'static [u64] = &{
+        let left = Left::LIMBS;
+        let right = Right::LIMBS;
+        assert!(
+            left.len() + right.len() == LEN,
+            "Length of type-level limb concatenation does not match"
+        );
+        let mut arr = [0; LEN];
+        let mut index = 0;
+        while index < left.len() {
+            arr[index] = left[index];
+            index += 1;
+        }
+        while index < LEN {
+            arr[index] = right[index - left.len()];
+            index += 1;
+        }
+        arr
+    };
+}
+
+/// Struct to lift an arbitrarily large integer to the type level, as its sign and a tree of [Limb]s.
+///
+/// Implementation detail of [TypeBigInt].
+pub struct BigInt<const NEGATIVE: bool, Limbs: TypeLimbs>(PhantomData<Limbs>);
+
+impl<const NEGATIVE: bool, Limbs: TypeLimbs> Sealed for BigInt<NEGATIVE, Limbs> {}
+
+/// Trait to work with integers too large for `u128` or `i128` at the type level.
+///
+/// Objects of this trait are automatically generated by the [overloaded_literals](macro@crate::overloaded_literals) macro.
+///
+/// The integer is represented by its sign and its magnitude.
+/// The magnitude is a list of `u64` limbs in little-endian order (least significant limb first),
+/// without trailing zero limbs.
+/// For example, `18446744073709551616` (which is `u64::MAX + 1`) has the limbs `[0, 1]`.
+pub trait TypeBigInt: Sealed {
+    /// Whether the literal was negative.
+    /// This associated constant is part of the **stable API** of `TypeBigInt`.
+    const NEGATIVE: bool;
+
+    /// The magnitude of the literal as little-endian `u64` limbs.
+    /// This associated constant is part of the **stable API** of `TypeBigInt`.
+    const LIMBS: &'static [u64];
+}
+
+impl<const NEGATIVE: bool, Limbs: TypeLimbs> TypeBigInt for BigInt<NEGATIVE, Limbs> {
+    const NEGATIVE: bool = NEGATIVE;
+    const LIMBS: &'static [u64] = Limbs::LIMBS;
+}
@@ -0,0 +1,85 @@
+//! Lifting of a byte string literal to the type level,
+//! to allow usage of a `const &'static [u8]` in generic const contexts
+//! (which is otherwise not allowed on stable Rust currently.)
+//!
+//! Items in this module need to be public as the types and structs contained within
+//! are built by the `overloaded_literals!` macro.
+//!
+//! However, **consider the contents of this module an implementation detail,
+//! and do not depend on these details directly in your code. They are subject to change**.
+//!
+//! The only API which can be considered public and is guaranteed, is [TypeBytes::BYTES].
+use crate::sealed::Sealed;
+use core::marker::PhantomData;
+
+/// The maximum number of bytes packed into a single [Chunk].
+pub const CHUNK_SIZE: usize = 16;
+
+/// Struct to lift up to [CHUNK_SIZE] bytes to the type level.
+///
+/// The bytes are packed little-endian into `VAL`:
+/// the first byte is stored in the lowest 8 bits.
+///
+/// Implementation detail of [TypeBytes].
+pub struct Chunk<const LEN: usize, const VAL: u128>;
+
+/// Struct to lift the concatenation of two [TypeBytes] to the type level.
+///
+/// `LEN` is the total length of `Left` and `Right`
+/// (which is passed separately so it can be used as the size of an array).
+///
+/// Implementation detail of [TypeBytes].
+pub struct Concat<const LEN: usize, Left, Right>(PhantomData<(Left, Right)>);
+
+impl<const LEN: usize, const VAL: u128> Sealed for Chunk<LEN, VAL> {}
+impl<const LEN: usize, Left, Right> Sealed for Concat<LEN, Left, Right> {}
+
+/// Trait to work with byte slices at the type level, allowing `&'static [u8]` in 'const generic' contexts.
+///
+/// Objects of this trait are automatically generated by the [overloaded_literals](macro@crate::overloaded_literals) macro.
+///
+/// It works exactly like [TypeStr](crate::TypeStr) (which builds on top of it),
+/// except that the bytes are not required to be valid UTF-8.
+pub trait TypeBytes: Sealed {
+    /// Turns the TypeBytes into its const `&'static [u8]` equivalent.
+    /// This associated constant is part of the **stable API** of `TypeBytes`.
+    const BYTES: &'static [u8];
+}
+
+impl<const LEN: usize, const VAL: u128> TypeBytes for Chunk<LEN, VAL> {
+    const BYTES: &'static [u8] = &{
+        assert!(
+            LEN <= CHUNK_SIZE,
+            "Type-level chunk contains too many bytes"
+        );
+        let mut arr = [0; LEN];
+        let mut index = 0;
+        while index < LEN {
+            arr[index] = (VAL >> (8 * index)) as u8;
+            index += 1;
+        }
+        arr
+    };
+}
+
+impl<const LEN: usize, Left: TypeBytes, Right: TypeBytes> TypeBytes for Concat<LEN, Left, Right> {
+    const BYTES: &'static [u8] = &{
+        let left = Left::BYTES;
+        let right = Right::BYTES;
+        assert!(
+            left.len() + right.len() == LEN,
+            "Length of type-level byte concatenation does not match"
+        );
+        let mut arr = [0; LEN];
+        let mut index = 0;
+        while index < left.len() {
+            arr[index] = left[index];
+            index += 1;
+        }
+        while index < LEN {
+            arr[index] = right[index - left.len()];
+            index += 1;
+        }
+        arr
+    };
+}
@@ -0,0 +1,32 @@
+//! Lifting of a C string literal to the type level,
+//! to allow usage of a `const &'static CStr` in generic const contexts
+//! (which is otherwise not allowed on stable Rust currently.)
+//!
+//! Items in this module need to be public as the types and structs contained within
+//! are built by the `overloaded_literals!` macro.
+//!
+//! However, **consider the contents of this module an implementation detail,
+//! and do not depend on these details directly in your code. They are subject to change**.
+//!
+//! The only API which can be considered public and is guaranteed, is [TypeCStr::CSTR].
+use crate::type_bytes::{Chunk, Concat, TypeBytes};
+use core::ffi::CStr;
+
+/// Trait to work with [CStr]s at the type level, allowing `&'static CStr` in 'const generic' contexts.
+///
+/// Objects of this trait are automatically generated by the [overloaded_literals](macro@crate::overloaded_literals) macro.
+///
+/// It works exactly like [TypeStr](crate::TypeStr) (c.f. [TypeBytes]),
+/// except that the list of bytes also contains the final nul terminator.
+pub trait TypeCStr: TypeBytes {
+    /// Turns the TypeCStr into its const `&'static CStr` equivalent.
+    /// This associated constant is part of the **stable API** of `TypeCStr`.
+    const CSTR: &'static CStr = {
+        crate::assert_valid_cstr(Self::BYTES);
+        // SAFETY: Checked right above
+        unsafe { CStr::from_bytes_with_nul_unchecked(Self::BYTES) }
+    };
+}
+
+impl<const LEN: usize, const VAL: u128> TypeCStr for Chunk<LEN, VAL> {}
+impl<const LEN: usize, Left: TypeBytes, Right: TypeBytes> TypeCStr for Concat<LEN, Left, Right> {}
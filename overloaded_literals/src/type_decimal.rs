@@ -0,0 +1,55 @@
+//! Lifting of the exact decimal digits of a float literal to the type level,
+//! to allow building decimal and fixed-point types from float literals without binary rounding.
+//!
+//! Items in this module need to be public as the types and structs contained within
+//! are built by the `overloaded_literals!` macro.
+//!
+//! However, **consider the contents of this module an implementation detail,
+//! and do not depend on these details directly in your code. They are subject to change**.
+//!
+//! The only API which can be considered public and is guaranteed, is [TypeDecimal::MANTISSA] and [TypeDecimal::EXPONENT]
+//! (as well as [TypeFloat::FLOAT]).
+
+use crate::sealed::Sealed;
+use crate::type_float::{Float, TypeFloat};
+
+/// Struct to lift a float literal to the type level, both as `f64` and as its exact decimal digits.
+///
+/// The literal's value is `MANTISSA * 10^EXPONENT`.
+///
+/// Implementation detail of [TypeDecimal].
+pub struct Decimal<const FLOAT_BITS: u64, const MANTISSA: i128, const EXPONENT: i32>;
+
+impl<const FLOAT_BITS: u64, const MANTISSA: i128, const EXPONENT: i32> Sealed
+    for Decimal<FLOAT_BITS, MANTISSA, EXPONENT>
+{
+}
+
+/// Trait to work with the exact decimal value of float literals at the type level.
+///
+/// Objects of this trait are automatically generated by the [overloaded_literals](macro@crate::overloaded_literals) macro,
+/// for every float literal whose digits fit in an `i128`.
+///
+/// The value of the literal is `MANTISSA * 10^EXPONENT`. The digits are kept as written, so `0.10` has a mantissa of `10` and an exponent of `-2`.
+pub trait TypeDecimal: TypeFloat {
+    /// All digits of the literal (including its sign) as a single integer.
+    /// This associated constant is part of the **stable API** of `TypeDecimal`.
+    const MANTISSA: i128;
+
+    /// The base-10 exponent by which the [MANTISSA](TypeDecimal::MANTISSA) is scaled.
+    /// This associated constant is part of the **stable API** of `TypeDecimal`.
+    const EXPONENT: i32;
+}
+
+impl<const FLOAT_BITS: u64, const MANTISSA: i128, const EXPONENT: i32> TypeFloat
+    for Decimal<FLOAT_BITS, MANTISSA, EXPONENT>
+{
+    const FLOAT: f64 = Float::<FLOAT_BITS>::FLOAT;
+}
+
+impl<const FLOAT_BITS: u64, const MANTISSA: i128, const EXPONENT: i32> TypeDecimal
+    for Decimal<FLOAT_BITS, MANTISSA, EXPONENT>
+{
+    const MANTISSA: i128 = MANTISSA;
+    const EXPONENT: i32 = EXPONENT;
+}
@@ -28,6 +28,8 @@ pub trait TypeFloat: Sealed {
     const FLOAT: f64;
 }
 
+// `f64::from_bits` itself is only `const` since Rust 1.83, which is above our MSRV.
+#[allow(unknown_lints, unnecessary_transmutes)]
 impl<const FLOAT_BITS: u64> TypeFloat for Float<FLOAT_BITS> {
     const FLOAT: f64 = {
         // SAFETY: This is a const version of f64::from_bits()
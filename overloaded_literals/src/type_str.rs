@@ -9,93 +9,56 @@
 //! and do not depend on these details directly in your code. They are subject to change**.
 //!
 //! The only API which can be considered public and is guaranteed, is [TypeStr::STR].
-use crate::sealed::Sealed;
-use tlist::{TCons, TList, TNil};
-
-/// Struct to lift a single u8 byte to the type level.
-///
-/// Implementation detail of [TypeStr].
-pub struct Byte<const VAL: u8>;
-
-/// Implementation detail of [Byte] to read out its contained value in a generic context
-pub trait ContainsByte: Sealed {
-    const BYTE: u8;
-}
-
-impl<const BYTE: u8> ContainsByte for Byte<BYTE> {
-    const BYTE: u8 = BYTE;
-}
-
-/// Size of the internal buffer used to build up a `&'static str` from a `TypeStr`.
-/// This is the larges allowed string literal that can be used with `FromLiteralStr`.
-///
-/// Currently defined as 32KiB.
-///
-/// The exact value needs to be fixed but is arbitrary.
-/// If you really need a larger value, please open a PR.
-// NOTE: Make sure this value is not smaller than the one in `overloaded_literals_macro`
-pub const MAX_STR_LIT_LEN: usize = 32768;
+//! To name the type of a literal, use [typestr!](crate::typestr).
+use crate::type_bytes::{Chunk, Concat, TypeBytes};
 
 /// Trait to work with [str]s at the type level, allowing `&'static str` in 'const generic' contexts.
 ///
 /// Objects of this trait are automatically generated by the [overloaded_literals](macro@crate::overloaded_literals) macro.
+/// To name the type of a particular literal yourself, use [typestr!](crate::typestr).
 ///
 /// # How it works
 /// _This information is not important for normal use of the library. It is only interesting if you want to work on the internals of the library, or are curious._
 ///
-/// Each string literal compiles down (using the macro) to its own type-level list (c.f. [trait@TList]),
-/// in which each of the elements in the list is a [Byte].
-/// These disparate [Byte] types are manipulated in a generic way
-/// because regardless of their content VAL, they all implement the [ContainsByte] trait.
+/// Each string literal compiles down (using the macro) to a balanced binary tree of types.
+/// The leaves of this tree are [Chunk]s, each of which packs up to 16 bytes little-endian into a single `u128` const parameter.
+/// The nodes are [Concat]s, which join together the bytes of their two halves.
 ///
-/// So for a string like
+/// So for a short string like
 /// ```compile_only
 /// "hello"
 /// ```
-/// the macro builds the type-level list:
+/// the macro builds a single chunk:
 /// ```compile_only
-/// TList![Byte<104>, Byte<101>, Byte<108>, Byte<108>, Byte<111>]
-/// // Which is syntactic sugar for:
-/// TCons<Byte<104>, TCons<Byte<101>, TCons<Byte<108>, TCons<Byte<108>, TCons<Byte<111>, TNil>>>>>
+/// Chunk<5, 0x6f6c6c6568>
+/// ```
+/// while a string of 40 bytes is split into chunks of 16, 16 and 8 bytes:
+/// ```compile_only
+/// Concat<40, Chunk<16, ...>, Concat<24, Chunk<16, ...>, Chunk<8, ...>>>
 /// ```
 ///
-/// The two implementations of the [TypeStr] trait (for [TNil] and [TCons]) then join together these u8 values
-/// from each of the elements in the type-level linked list,
-/// putting them in a large const array of fixed size.
-/// Finally, the prefix of this large const array is returned as a &'static [u8] slice cast to a &'static str.
+/// The [TypeBytes] implementations of [Chunk] and [Concat] then each build their const array
+/// of exactly `LEN` bytes in a single pass.
+/// This array is returned as a &'static [u8] slice by [TypeBytes],
+/// which [TypeStr] checks to be valid UTF-8 and turns into a &'static str.
 ///
-/// We need to use a large const array *of fixed size* because:
-/// - The array type in the implementation cannot depend on the generic const type parameter. (blocking feature: `generic_const_exprs`)
-/// - Memory allocation is also of course not possible in const context.
-pub trait TypeStr: TList + Sealed {
-    #[doc(hidden)]
-    const V: [u8; MAX_STR_LIT_LEN];
-
+/// Because the tree is balanced, its depth only grows logarithmically with the length of the string,
+/// and every byte is copied once per level, which keeps the compile-time cost of long strings low.
+///
+/// The length needs to be passed separately (rather than summing the lengths of the halves)
+/// because the array type in the implementation cannot depend on an associated constant of a generic type parameter.
+/// (blocking feature: `generic_const_exprs`)
+pub trait TypeStr: TypeBytes {
     /// Turns the TypeStr into its const `&'static str` equivalent.
     /// This associated constant is part of the **stable API** of `TypeStr`.
-    const STR: &'static str = {
-        let ptr = &Self::V as *const u8;
-        let slice = unsafe { core::slice::from_raw_parts::<'static, u8>(ptr, Self::LEN) };
-        unsafe { core::str::from_utf8_unchecked(slice) }
+    ///
+    /// The bytes are checked to be valid UTF-8 at compile-time,
+    /// so a hand-written type which is not results in a compile error.
+    const STR: &'static str = match core::str::from_utf8(Self::BYTES) {
+        Ok(str) => str,
+        Err(_) => panic!("Type-level string is not valid UTF-8"),
     };
 }
 
-impl TypeStr for TNil {
-    const V: [u8; MAX_STR_LIT_LEN] = { [0; MAX_STR_LIT_LEN] };
-}
-
-impl<First: ContainsByte, Rest: TypeStr> TypeStr for TCons<First, Rest> {
-    const V: [u8; MAX_STR_LIT_LEN] = {
-        assert!(Self::LEN <= MAX_STR_LIT_LEN);
-
-        let mut arr: [u8; MAX_STR_LIT_LEN] = [0; MAX_STR_LIT_LEN];
-
-        arr[0] = First::BYTE;
-        let mut i = 0;
-        while i < Rest::LEN {
-            arr[i + 1] = Rest::V[i];
-            i += 1;
-        }
-        arr
-    };
-}
+impl<const LEN: usize, const VAL: u128> TypeStr for Chunk<LEN, VAL> {}
+impl<const LEN: usize, Left: TypeBytes, Right: TypeBytes> TypeStr for Concat<LEN, Left, Right> {}
@@ -0,0 +1,11 @@
+use overloaded_literals::overloaded_literals;
+
+struct Greeting;
+struct Temperature;
+
+#[overloaded_literals]
+fn main() {
+    let _greeting: Greeting = "hello";
+    let _temperature: Temperature = -5;
+    let _ratio: Temperature = -0.5;
+}
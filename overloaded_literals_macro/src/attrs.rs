@@ -0,0 +1,160 @@
+//! Helpers to inspect and strip the attributes that the macro understands.
+use syn::visit_mut::{self, VisitMut};
+use syn::{
+    Arm, Attribute, Expr, ImplItemConst, ImplItemFn, Item, Local, StmtMacro, TraitItemConst,
+    TraitItemFn,
+};
+
+/// Name of the attribute that opts an expression, statement or item out of literal overloading.
+const NO_OVERLOAD: &str = "no_overload";
+
+fn is_overloaded_literals_attr(attr: &Attribute) -> bool {
+    matches!(attr.path().segments.last(), Some(segment) if segment.ident == "overloaded_literals")
+}
+
+/// Whether the item is annotated with `#[overloaded_literals]` itself.
+pub(crate) fn is_annotated(item: &Item) -> bool {
+    let attrs = match item {
+        Item::Fn(item_fn) => &item_fn.attrs,
+        Item::Impl(item_impl) => &item_impl.attrs,
+        Item::Trait(item_trait) => &item_trait.attrs,
+        Item::Mod(item_mod) => &item_mod.attrs,
+        _ => return false,
+    };
+    attrs.iter().any(is_overloaded_literals_attr)
+}
+
+/// Removes all `#[no_overload]` attributes from `attrs`,
+/// returning whether there were any.
+pub(crate) fn take_no_overload(attrs: &mut Vec<Attribute>) -> bool {
+    let len = attrs.len();
+    attrs.retain(|attr| !attr.path().is_ident(NO_OVERLOAD));
+    attrs.len() != len
+}
+
+/// Visitor which removes every `#[no_overload]` attribute in a subtree, without rewriting any literals.
+///
+/// Used on subtrees that are skipped because of a `#[no_overload]`,
+/// as nested `#[no_overload]` attributes would otherwise end up in the output
+/// (where they are unknown to the compiler).
+pub(crate) struct StripNoOverload;
+
+impl VisitMut for StripNoOverload {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Some(attrs) = expr_attrs_mut(expr) {
+            take_no_overload(attrs);
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+
+    fn visit_local_mut(&mut self, local: &mut Local) {
+        take_no_overload(&mut local.attrs);
+        visit_mut::visit_local_mut(self, local);
+    }
+
+    fn visit_stmt_macro_mut(&mut self, stmt_macro: &mut StmtMacro) {
+        take_no_overload(&mut stmt_macro.attrs);
+        visit_mut::visit_stmt_macro_mut(self, stmt_macro);
+    }
+
+    fn visit_arm_mut(&mut self, arm: &mut Arm) {
+        take_no_overload(&mut arm.attrs);
+        visit_mut::visit_arm_mut(self, arm);
+    }
+
+    fn visit_item_mut(&mut self, item: &mut Item) {
+        // Nested annotated items are expanded separately, and strip their own attributes.
+        if is_annotated(item) {
+            return;
+        }
+        if let Some(attrs) = item_attrs_mut(item) {
+            take_no_overload(attrs);
+        }
+        visit_mut::visit_item_mut(self, item);
+    }
+
+    fn visit_impl_item_fn_mut(&mut self, impl_item_fn: &mut ImplItemFn) {
+        take_no_overload(&mut impl_item_fn.attrs);
+        visit_mut::visit_impl_item_fn_mut(self, impl_item_fn);
+    }
+
+    fn visit_trait_item_fn_mut(&mut self, trait_item_fn: &mut TraitItemFn) {
+        take_no_overload(&mut trait_item_fn.attrs);
+        visit_mut::visit_trait_item_fn_mut(self, trait_item_fn);
+    }
+
+    fn visit_impl_item_const_mut(&mut self, impl_item_const: &mut ImplItemConst) {
+        take_no_overload(&mut impl_item_const.attrs);
+        visit_mut::visit_impl_item_const_mut(self, impl_item_const);
+    }
+
+    fn visit_trait_item_const_mut(&mut self, trait_item_const: &mut TraitItemConst) {
+        take_no_overload(&mut trait_item_const.attrs);
+        visit_mut::visit_trait_item_const_mut(self, trait_item_const);
+    }
+}
+
+pub(crate) fn expr_attrs_mut(expr: &mut Expr) -> Option<&mut Vec<Attribute>> {
+    match expr {
+        Expr::Array(expr) => Some(&mut expr.attrs),
+        Expr::Assign(expr) => Some(&mut expr.attrs),
+        Expr::Async(expr) => Some(&mut expr.attrs),
+        Expr::Await(expr) => Some(&mut expr.attrs),
+        Expr::Binary(expr) => Some(&mut expr.attrs),
+        Expr::Block(expr) => Some(&mut expr.attrs),
+        Expr::Break(expr) => Some(&mut expr.attrs),
+        Expr::Call(expr) => Some(&mut expr.attrs),
+        Expr::Cast(expr) => Some(&mut expr.attrs),
+        Expr::Closure(expr) => Some(&mut expr.attrs),
+        Expr::Const(expr) => Some(&mut expr.attrs),
+        Expr::Continue(expr) => Some(&mut expr.attrs),
+        Expr::Field(expr) => Some(&mut expr.attrs),
+        Expr::ForLoop(expr) => Some(&mut expr.attrs),
+        Expr::Group(expr) => Some(&mut expr.attrs),
+        Expr::If(expr) => Some(&mut expr.attrs),
+        Expr::Index(expr) => Some(&mut expr.attrs),
+        Expr::Infer(expr) => Some(&mut expr.attrs),
+        Expr::Let(expr) => Some(&mut expr.attrs),
+        Expr::Lit(expr) => Some(&mut expr.attrs),
+        Expr::Loop(expr) => Some(&mut expr.attrs),
+        Expr::Macro(expr) => Some(&mut expr.attrs),
+        Expr::Match(expr) => Some(&mut expr.attrs),
+        Expr::MethodCall(expr) => Some(&mut expr.attrs),
+        Expr::Paren(expr) => Some(&mut expr.attrs),
+        Expr::Path(expr) => Some(&mut expr.attrs),
+        Expr::Range(expr) => Some(&mut expr.attrs),
+        Expr::Reference(expr) => Some(&mut expr.attrs),
+        Expr::Repeat(expr) => Some(&mut expr.attrs),
+        Expr::Return(expr) => Some(&mut expr.attrs),
+        Expr::Struct(expr) => Some(&mut expr.attrs),
+        Expr::Try(expr) => Some(&mut expr.attrs),
+        Expr::TryBlock(expr) => Some(&mut expr.attrs),
+        Expr::Tuple(expr) => Some(&mut expr.attrs),
+        Expr::Unary(expr) => Some(&mut expr.attrs),
+        Expr::Unsafe(expr) => Some(&mut expr.attrs),
+        Expr::While(expr) => Some(&mut expr.attrs),
+        Expr::Yield(expr) => Some(&mut expr.attrs),
+        _ => None,
+    }
+}
+
+pub(crate) fn item_attrs_mut(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Const(item) => Some(&mut item.attrs),
+        Item::Enum(item) => Some(&mut item.attrs),
+        Item::ExternCrate(item) => Some(&mut item.attrs),
+        Item::Fn(item) => Some(&mut item.attrs),
+        Item::ForeignMod(item) => Some(&mut item.attrs),
+        Item::Impl(item) => Some(&mut item.attrs),
+        Item::Macro(item) => Some(&mut item.attrs),
+        Item::Mod(item) => Some(&mut item.attrs),
+        Item::Static(item) => Some(&mut item.attrs),
+        Item::Struct(item) => Some(&mut item.attrs),
+        Item::Trait(item) => Some(&mut item.attrs),
+        Item::TraitAlias(item) => Some(&mut item.attrs),
+        Item::Type(item) => Some(&mut item.attrs),
+        Item::Union(item) => Some(&mut item.attrs),
+        Item::Use(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
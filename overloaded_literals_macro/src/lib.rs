@@ -1,123 +1,623 @@
 #![doc = include_str!("../README.md")]
 extern crate proc_macro;
+mod attrs;
+mod macros;
+mod patterns;
+
+use attrs::{expr_attrs_mut, is_annotated, item_attrs_mut, take_no_overload, StripNoOverload};
+use core::fmt::Display;
 use proc_macro::TokenStream;
-use quote::{__private::Span, quote};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
-    fold::Fold, parse_macro_input, parse_quote_spanned, spanned::Spanned, Expr, ExprLit, ExprUnary,
-    ItemFn, Lit, UnOp,
+    fold::Fold,
+    meta::ParseNestedMeta,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote_spanned,
+    spanned::Spanned,
+    visit_mut::VisitMut,
+    Arm, Attribute, ConstParam, Expr, ExprConst, ExprLit, ExprMacro, ExprRepeat, ExprUnary,
+    GenericArgument, ImplItemConst, ImplItemFn, Item, ItemConst, ItemFn, ItemStatic, Lit, LitFloat,
+    LitInt, Local, Pat, Path, StmtMacro, Token, TraitItemConst, TraitItemFn, Type, UnOp, Variant,
 };
 
-struct Args;
+/// The arguments passed to the attribute, e.g. `#[overloaded_literals(int, str)]`.
+///
+/// Each flag indicates whether literals of that kind are overloaded.
+/// `patterns` indicates whether literal patterns are rewritten as well.
+/// `arrays` indicates whether array expressions like `[1, 2, 3]` are rewritten as well.
+/// `strict` turns literals with a built-in suffix (like `10usize`) and unsupported literal kinds into compile errors.
+/// `krate` is the path to the `overloaded_literals` crate, which can be overridden with `crate = path`.
+///
+/// `in_const` is not an argument but tracks whether we are currently inside a const context
+/// (like a `const` item or `const fn`), where `into_self()` cannot be called.
+struct Args {
+    int: bool,
+    float: bool,
+    bool: bool,
+    str: bool,
+    char: bool,
+    byte: bool,
+    byte_str: bool,
+    c_str: bool,
+    patterns: bool,
+    arrays: bool,
+    strict: bool,
+    krate: Option<Path>,
+    in_const: bool,
+}
 
-fn wrap_signed(unsigned_expr_lit: &ExprLit, span: Span) -> Option<syn::Expr> {
-    match unsigned_expr_lit {
-        ExprLit {
-            attrs,
-            lit: Lit::Int(lit_int),
-        } => {
-            if !attrs.is_empty() {
-                return None;
-            }
-            if lit_int.suffix() != "" {
-                return None;
-            }
-            let res = parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralSigned::<-#lit_int>::into_self() );
-            Some(res)
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            int: true,
+            float: true,
+            bool: true,
+            str: true,
+            char: true,
+            byte: true,
+            byte_str: true,
+            c_str: true,
+            patterns: false,
+            arrays: false,
+            strict: false,
+            krate: None,
+            in_const: false,
+        }
+    }
+}
+
+impl Args {
+    fn none() -> Self {
+        Args {
+            int: false,
+            float: false,
+            bool: false,
+            str: false,
+            char: false,
+            byte: false,
+            byte_str: false,
+            c_str: false,
+            patterns: false,
+            arrays: false,
+            strict: false,
+            krate: None,
+            in_const: false,
         }
-        ExprLit {
-            attrs,
-            lit: Lit::Float(lit_float),
-        } => {
-            if !attrs.is_empty() {
-                return None;
+    }
+
+    fn parse_meta(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("crate") {
+            if self.krate.is_some() {
+                return Err(meta.error("argument is listed more than once"));
             }
-            if lit_float.suffix() != "" {
-                return None;
+            self.krate = Some(meta.value()?.parse()?);
+            return Ok(());
+        }
+        let kind = if meta.path.is_ident("int") {
+            &mut self.int
+        } else if meta.path.is_ident("float") {
+            &mut self.float
+        } else if meta.path.is_ident("bool") {
+            &mut self.bool
+        } else if meta.path.is_ident("str") {
+            &mut self.str
+        } else if meta.path.is_ident("char") {
+            &mut self.char
+        } else if meta.path.is_ident("byte") {
+            &mut self.byte
+        } else if meta.path.is_ident("byte_str") {
+            &mut self.byte_str
+        } else if meta.path.is_ident("c_str") {
+            &mut self.c_str
+        } else if meta.path.is_ident("patterns") {
+            &mut self.patterns
+        } else if meta.path.is_ident("arrays") {
+            &mut self.arrays
+        } else if meta.path.is_ident("strict") {
+            &mut self.strict
+        } else {
+            return Err(meta.error(
+                "unsupported argument; expected one of `int`, `float`, `bool`, `str`, `char`, `byte`, `byte_str`, `c_str`, `patterns`, `arrays`, `strict` or `crate = path`",
+            ));
+        };
+        if *kind {
+            return Err(meta.error("argument is listed more than once"));
+        }
+        *kind = true;
+        Ok(())
+    }
+
+    /// If no literal kinds were listed explicitly, all of them are overloaded.
+    fn or_default(self) -> Self {
+        if self.int
+            || self.float
+            || self.bool
+            || self.str
+            || self.char
+            || self.byte
+            || self.byte_str
+            || self.c_str
+        {
+            self
+        } else {
+            Args {
+                int: true,
+                float: true,
+                bool: true,
+                str: true,
+                char: true,
+                byte: true,
+                byte_str: true,
+                c_str: true,
+                ..self
             }
-            let float = lit_float.base10_parse::<f64>().unwrap();
-            let float_bits: u64 = (-float).to_bits();
-            let res = parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralFloat::<::overloaded_literals::type_float::Float<#float_bits>>::into_self());
-            Some(res)
         }
+    }
+
+    /// The path to the `overloaded_literals` crate to use in the generated code,
+    /// spanned to point at the literal at `span`.
+    fn krate(&self, span: Span) -> TokenStream2 {
+        match &self.krate {
+            Some(krate) => respan(krate, span),
+            None => respan(quote!(::overloaded_literals), span),
+        }
+    }
+
+    /// Runs `fold` with `in_const` set to the given value, restoring the previous value afterwards.
+    fn with_const_context<T>(&mut self, in_const: bool, fold: impl FnOnce(&mut Self) -> T) -> T {
+        let outer = core::mem::replace(&mut self.in_const, in_const);
+        let res = fold(self);
+        self.in_const = outer;
+        res
+    }
 
-        _ => None,
+    fn overloads(&self, lit: &Lit) -> bool {
+        match lit {
+            Lit::Int(_) => self.int,
+            Lit::Float(_) => self.float,
+            Lit::Bool(_) => self.bool,
+            Lit::Str(_) => self.str,
+            Lit::Char(_) => self.char,
+            Lit::Byte(_) => self.byte,
+            Lit::ByteStr(_) => self.byte_str,
+            Lit::CStr(_) => self.c_str,
+            _ => false,
+        }
     }
 }
 
-// NOTE: Make sure this value is not larger than the one in `overloaded_literals_macro`
-const MAX_STR_LIT_LEN: usize = 32768;
+// NOTE: This must be equal to `overloaded_literals::type_bytes::CHUNK_SIZE`.
+// (We cannot import it, as `overloaded_literals` depends on this crate.)
+const CHUNK_SIZE: usize = 16;
 
-fn wrap_unsigned_or_str(expr_lit: ExprLit, span: Span) -> syn::Expr {
-    match &expr_lit {
-        ExprLit {
-            attrs,
-            lit: Lit::Int(lit_int),
-        } => {
-            if !attrs.is_empty() {
-                return Expr::Lit(expr_lit);
-            }
-            if lit_int.suffix() != "" {
-                return Expr::Lit(expr_lit);
+/// Literal suffixes which Rust itself understands. Literals using these are left alone.
+const BUILTIN_SUFFIXES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize", "f16",
+    "f32", "f64", "f128",
+];
+
+/// Makes every token of `tokens` point at the location of the original literal at `span`.
+///
+/// Tokens created by the macro (like the `usize` and `u128` generic arguments of a type-level string)
+/// otherwise point at the macro invocation, and so would any compile error mentioning them.
+/// They keep the hygiene of the macro invocation, so lints still treat them as generated code.
+fn respan(tokens: impl ToTokens, span: Span) -> TokenStream2 {
+    let span = Span::call_site().located_at(span);
+    tokens
+        .into_token_stream()
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
+
+/// Builds an expression which fails to compile with `message`, pointing at the literal at `span`.
+fn refuse(span: Span, message: impl Display) -> Expr {
+    Expr::Verbatim(syn::Error::new(span, message).to_compile_error())
+}
+
+/// Converts the decimal digits of an integer literal into `u64` limbs, least significant limb first.
+fn bigint_limbs(digits: &str) -> Vec<u64> {
+    let mut limbs: Vec<u64> = Vec::new();
+    for digit in digits.bytes() {
+        let mut carry = u128::from(digit - b'0');
+        for limb in limbs.iter_mut() {
+            let val = u128::from(*limb) * 10 + carry;
+            *limb = val as u64;
+            carry = val >> 64;
+        }
+        if carry != 0 {
+            limbs.push(carry as u64);
+        }
+    }
+    limbs
+}
+
+/// Returns the radix and the number of written digits of an integer literal, e.g. `(2, 8)` for `0b0000_1111`.
+fn radix_and_digits(lit_int: &LitInt) -> (u32, u32) {
+    let repr = lit_int.to_string();
+    let repr = repr.strip_suffix(lit_int.suffix()).unwrap_or(&repr);
+    let (radix, digits) = match repr.get(..2) {
+        Some("0x") => (16, &repr[2..]),
+        Some("0o") => (8, &repr[2..]),
+        Some("0b") => (2, &repr[2..]),
+        _ => (10, repr),
+    };
+    (
+        radix,
+        digits.chars().filter(|char| *char != '_').count() as u32,
+    )
+}
+
+/// Splits the digits of a float literal like `12.5e-3` into an integer mantissa (`125`) and base-10 exponent (`-4`).
+///
+/// Returns `None` if these do not fit in an `i128` and `i32` respectively.
+fn decimal_parts(digits: &str) -> Option<(i128, i32)> {
+    let (significand, exponent) = match digits.find(['e', 'E']) {
+        Some(index) => (&digits[..index], digits[index + 1..].parse::<i32>().ok()?),
+        None => (digits, 0),
+    };
+    let (int, frac) = significand.split_once('.').unwrap_or((significand, ""));
+    let mantissa = format!("{}{}", int, frac).parse::<i128>().ok()?;
+    let exponent = exponent.checked_sub(i32::try_from(frac.len()).ok()?)?;
+    Some((mantissa, exponent))
+}
+
+impl Args {
+    /// Builds the expression which turns the literal (represented by the `generic` argument of `trait_name`) into a value.
+    ///
+    /// Outside of const contexts this calls `into_self()`.
+    /// Inside const contexts, it instead uses the associated `VALUE` of the `ConstFromLiteral*` sibling trait.
+    fn construct(&self, trait_name: &str, generic: TokenStream2, span: Span) -> Expr {
+        let krate = self.krate(span);
+        if self.in_const {
+            // `FromLiteralByteStr` -> `const_byte_str`, etc.
+            let mut fun = String::from("const");
+            for char in trait_name.trim_start_matches("FromLiteral").chars() {
+                if char.is_uppercase() {
+                    fun.push('_');
+                }
+                fun.push(char.to_ascii_lowercase());
             }
-            let res = parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralUnsigned::<#expr_lit>::into_self());
-            res
+            let fun = format_ident!("{}", fun, span = span);
+            parse_quote_spanned!(span=> #krate::__private::#fun::<#generic, _>())
+        } else {
+            let trait_ident = format_ident!("{}", trait_name, span = span);
+            parse_quote_spanned!(span=> #krate::#trait_ident::<#generic>::into_self())
+        }
+    }
+
+    /// Builds the expression for a literal with a custom (non-builtin) suffix like the `ms` in `30ms`.
+    ///
+    /// The suffix is lifted to the type level just like string literals,
+    /// and passed as first generic argument to the `FromLiteralSuffixed*` variant of `trait_name`.
+    fn construct_suffixed(
+        &self,
+        trait_name: &str,
+        suffix: &str,
+        generic: TokenStream2,
+        span: Span,
+    ) -> Expr {
+        let suffix = self.build_type_bytes(suffix.as_bytes(), span);
+        let trait_name = trait_name.replacen("FromLiteral", "FromLiteralSuffixed", 1);
+        self.construct(&trait_name, quote_spanned!(span=> #suffix, #generic), span)
+    }
+
+    /// Builds the expression for an integer literal which does not fit in a `u128` (or `i128` when `negative`).
+    fn construct_big_int(&self, lit_int: &LitInt, negative: bool, span: Span) -> Expr {
+        let limbs = bigint_limbs(lit_int.base10_digits());
+        let limbs = self.build_type_limbs(&limbs, span);
+        let krate = self.krate(span);
+        let negative = respan(negative, span);
+        let type_big_int = quote_spanned!(span=> #krate::type_bigint::BigInt<#negative, #limbs>);
+        self.construct("FromLiteralBigInt", type_big_int, span)
+    }
+
+    /// Builds the balanced tree of limbs used by `TypeBigInt`, in the same way as `build_type_bytes`.
+    ///
+    /// Literals which do not fit in a `u128` always have at least two limbs, so the tree is never empty.
+    fn build_type_limbs(&self, limbs: &[u64], span: Span) -> TokenStream2 {
+        let krate = self.krate(span);
+        if let [limb] = limbs {
+            let limb = respan(limb, span);
+            return quote_spanned!(span=> #krate::type_bigint::Limb<#limb>);
+        }
+        let len = limbs.len();
+        let (left, right) = limbs.split_at(len / 2);
+        let left = self.build_type_limbs(left, span);
+        let right = self.build_type_limbs(right, span);
+        let len = respan(len, span);
+        quote_spanned!(span=> #krate::type_bigint::Concat<#len, #left, #right>)
+    }
+
+    /// In `strict` mode, builds the compile error for a literal with a built-in suffix like `10usize`,
+    /// which is never overloaded.
+    fn refuse_builtin_suffix(&self, suffix: &str, span: Span) -> Option<Expr> {
+        if !self.strict {
+            return None;
         }
-        ExprLit {
-            attrs,
-            lit: Lit::Str(lit_str),
-        } => {
-            if !attrs.is_empty() {
-                return Expr::Lit(expr_lit);
+        Some(refuse(
+            span,
+            format!(
+                "literals with the built-in suffix `{}` are not overloaded (denied by `strict`); remove the suffix or mark the literal with `#[no_overload]`",
+                suffix
+            ),
+        ))
+    }
+
+    /// Builds the compile error for a custom suffix on a kind of literal which does not support them, like `b"x"foo`.
+    ///
+    /// Custom suffixes are only supported on integer, float and string literals.
+    fn refuse_custom_suffix(kind: &str, suffix: &str, span: Span) -> Expr {
+        refuse(
+            span,
+            format!(
+                "the custom suffix `{}` is not supported on {} literals; only integer, float and string literals can have a custom suffix",
+                suffix, kind
+            ),
+        )
+    }
+
+    fn wrap_signed(&self, unsigned_expr_lit: &ExprLit, span: Span) -> Option<syn::Expr> {
+        match unsigned_expr_lit {
+            ExprLit {
+                attrs,
+                lit: Lit::Int(lit_int),
+            } => {
+                if !attrs.is_empty() {
+                    return None;
+                }
+                match lit_int.suffix() {
+                    "" if format!("-{}", lit_int.base10_digits())
+                        .parse::<i128>()
+                        .is_err() =>
+                    {
+                        Some(self.construct_big_int(lit_int, true, span))
+                    }
+                    "" => Some(self.construct(
+                        "FromLiteralSigned",
+                        quote_spanned!(span=> -#lit_int),
+                        span,
+                    )),
+                    suffix if BUILTIN_SUFFIXES.contains(&suffix) => {
+                        self.refuse_builtin_suffix(suffix, span)
+                    }
+                    suffix => {
+                        let unsuffixed = LitInt::new(lit_int.base10_digits(), span);
+                        Some(self.construct_suffixed(
+                            "FromLiteralSigned",
+                            suffix,
+                            quote_spanned!(span=> -#unsuffixed),
+                            span,
+                        ))
+                    }
+                }
             }
-            if lit_str.value().len() > MAX_STR_LIT_LEN {
-                return Expr::Lit(expr_lit);
+            ExprLit {
+                attrs,
+                lit: Lit::Float(lit_float),
+            } => {
+                if !attrs.is_empty() {
+                    return None;
+                }
+                if BUILTIN_SUFFIXES.contains(&lit_float.suffix()) {
+                    return self.refuse_builtin_suffix(lit_float.suffix(), span);
+                }
+                let type_float = self.build_type_float(lit_float, true, span);
+                match lit_float.suffix() {
+                    "" => Some(self.construct("FromLiteralFloat", type_float, span)),
+                    suffix => {
+                        Some(self.construct_suffixed("FromLiteralFloat", suffix, type_float, span))
+                    }
+                }
             }
-            build_typestr(&lit_str.value(), span)
+
+            _ => None,
         }
-        ExprLit {
-            attrs,
-            lit: Lit::Bool(_),
-        } => {
-            if !attrs.is_empty() {
-                return Expr::Lit(expr_lit);
+    }
+
+    fn wrap_unsigned_or_str(&self, expr_lit: ExprLit, span: Span) -> syn::Expr {
+        match &expr_lit {
+            ExprLit {
+                attrs,
+                lit: Lit::Int(lit_int),
+            } => {
+                if !attrs.is_empty() {
+                    return Expr::Lit(expr_lit);
+                }
+                match lit_int.suffix() {
+                    "" if lit_int.base10_parse::<u128>().is_err() => {
+                        self.construct_big_int(lit_int, false, span)
+                    }
+                    "" => match radix_and_digits(lit_int) {
+                        (10, _) => self.construct("FromLiteralUnsigned", quote!(#expr_lit), span),
+                        (radix, digits) => {
+                            let radix = respan(radix, span);
+                            let digits = respan(digits, span);
+                            self.construct(
+                                "FromLiteralRadix",
+                                quote_spanned!(span=> #expr_lit, #radix, #digits),
+                                span,
+                            )
+                        }
+                    },
+                    suffix if BUILTIN_SUFFIXES.contains(&suffix) => self
+                        .refuse_builtin_suffix(suffix, span)
+                        .unwrap_or(Expr::Lit(expr_lit)),
+                    suffix => {
+                        let unsuffixed = LitInt::new(lit_int.base10_digits(), span);
+                        self.construct_suffixed(
+                            "FromLiteralUnsigned",
+                            suffix,
+                            quote!(#unsuffixed),
+                            span,
+                        )
+                    }
+                }
             }
-            let res = parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralBool::<#expr_lit>::into_self());
-            res
-        }
-        ExprLit {
-            attrs,
-            lit: Lit::Float(lit_float),
-        } => {
-            if !attrs.is_empty() {
-                return Expr::Lit(expr_lit);
+            ExprLit {
+                attrs,
+                lit: Lit::Str(lit_str),
+            } => {
+                if !attrs.is_empty() {
+                    return Expr::Lit(expr_lit);
+                }
+                let bytes = self.build_type_bytes(lit_str.value().as_bytes(), span);
+                match lit_str.suffix() {
+                    "" => self.construct("FromLiteralStr", bytes, span),
+                    suffix => self.construct_suffixed("FromLiteralStr", suffix, bytes, span),
+                }
+            }
+            ExprLit {
+                attrs,
+                lit: Lit::ByteStr(lit_byte_str),
+            } => {
+                if !attrs.is_empty() {
+                    return Expr::Lit(expr_lit);
+                }
+                if !lit_byte_str.suffix().is_empty() {
+                    return Self::refuse_custom_suffix("byte string", lit_byte_str.suffix(), span);
+                }
+                let bytes = self.build_type_bytes(&lit_byte_str.value(), span);
+                self.construct("FromLiteralByteStr", bytes, span)
+            }
+            ExprLit {
+                attrs,
+                lit: Lit::CStr(lit_c_str),
+            } => {
+                if !attrs.is_empty() {
+                    return Expr::Lit(expr_lit);
+                }
+                if !lit_c_str.suffix().is_empty() {
+                    return Self::refuse_custom_suffix("C string", lit_c_str.suffix(), span);
+                }
+                let value = lit_c_str.value();
+                let bytes = self.build_type_bytes(value.as_bytes_with_nul(), span);
+                self.construct("FromLiteralCStr", bytes, span)
+            }
+            ExprLit {
+                attrs,
+                lit: Lit::Bool(_),
+            } => {
+                if !attrs.is_empty() {
+                    return Expr::Lit(expr_lit);
+                }
+                self.construct("FromLiteralBool", quote!(#expr_lit), span)
+            }
+            ExprLit {
+                attrs,
+                lit: Lit::Char(lit_char),
+            } => {
+                if !attrs.is_empty() {
+                    return Expr::Lit(expr_lit);
+                }
+                if !lit_char.suffix().is_empty() {
+                    return Self::refuse_custom_suffix("char", lit_char.suffix(), span);
+                }
+                self.construct("FromLiteralChar", quote!(#expr_lit), span)
             }
-            if lit_float.suffix() != "" {
-                return Expr::Lit(expr_lit);
+            ExprLit {
+                attrs,
+                lit: Lit::Byte(lit_byte),
+            } => {
+                if !attrs.is_empty() {
+                    return Expr::Lit(expr_lit);
+                }
+                if !lit_byte.suffix().is_empty() {
+                    return Self::refuse_custom_suffix("byte", lit_byte.suffix(), span);
+                }
+                self.construct("FromLiteralByte", quote!(#expr_lit), span)
+            }
+            ExprLit {
+                attrs,
+                lit: Lit::Float(lit_float),
+            } => {
+                if !attrs.is_empty() {
+                    return Expr::Lit(expr_lit);
+                }
+                if BUILTIN_SUFFIXES.contains(&lit_float.suffix()) {
+                    return self
+                        .refuse_builtin_suffix(lit_float.suffix(), span)
+                        .unwrap_or(Expr::Lit(expr_lit));
+                }
+                let type_float = self.build_type_float(lit_float, false, span);
+                match lit_float.suffix() {
+                    "" => self.construct("FromLiteralFloat", type_float, span),
+                    suffix => self.construct_suffixed("FromLiteralFloat", suffix, type_float, span),
+                }
             }
-            let float_bits: u64 = lit_float.base10_parse::<f64>().unwrap().to_bits();
-            let res = parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralFloat::<::overloaded_literals::type_float::Float<#float_bits>>::into_self());
-            res
+            _ if self.strict => refuse(
+                span,
+                "this kind of literal is not supported by `overloaded_literals` (denied by `strict`); mark it with `#[no_overload]`",
+            ),
+            other => Expr::Lit(other.clone()),
+        }
+    }
+
+    /// Builds the type-level float used by `TypeFloat`.
+    ///
+    /// Whenever possible this is a `Decimal` which also contains the exact digits of the literal (c.f. `TypeDecimal`).
+    fn build_type_float(&self, lit_float: &LitFloat, negative: bool, span: Span) -> TokenStream2 {
+        let krate = self.krate(span);
+        let mut float = lit_float.base10_parse::<f64>().unwrap();
+        if negative {
+            float = -float;
+        }
+        let float_bits = respan(float.to_bits(), span);
+        match decimal_parts(lit_float.base10_digits()) {
+            Some((mut mantissa, exponent)) => {
+                if negative {
+                    mantissa = -mantissa;
+                }
+                let mantissa = respan(mantissa, span);
+                let exponent = respan(exponent, span);
+                quote_spanned!(span=> #krate::type_decimal::Decimal<#float_bits, #mantissa, #exponent>)
+            }
+            None => quote_spanned!(span=> #krate::type_float::Float<#float_bits>),
         }
-        other => Expr::Lit(other.clone()),
     }
-}
 
-fn build_typestr(string: &str, span: Span) -> syn::Expr {
-    let mut res = quote!(::tlist::TNil);
-    for byte in string.as_bytes().iter().rev() {
-        res = parse_quote_spanned!(span=> ::tlist::TCons<::overloaded_literals::type_str::Byte<#byte>, #res>);
+    /// Builds the type-level byte string used by `TypeStr`, `TypeBytes` and `TypeCStr`.
+    ///
+    /// The bytes are packed into `Chunk`s of at most `CHUNK_SIZE` bytes,
+    /// which are joined into a balanced tree of `Concat`s to keep the nesting depth logarithmic.
+    fn build_type_bytes(&self, bytes: &[u8], span: Span) -> TokenStream2 {
+        let krate = self.krate(span);
+        let len = bytes.len();
+        if len <= CHUNK_SIZE {
+            let val = bytes
+                .iter()
+                .rev()
+                .fold(0u128, |acc, &byte| (acc << 8) | u128::from(byte));
+            let len = respan(len, span);
+            let val = respan(val, span);
+            return quote_spanned!(span=> #krate::type_bytes::Chunk<#len, #val>);
+        }
+        let chunks = len.div_ceil(CHUNK_SIZE);
+        let (left, right) = bytes.split_at(chunks / 2 * CHUNK_SIZE);
+        let left = self.build_type_bytes(left, span);
+        let right = self.build_type_bytes(right, span);
+        let len = respan(len, span);
+        quote_spanned!(span=> #krate::type_bytes::Concat<#len, #left, #right>)
     }
-    let res =
-        parse_quote_spanned!(span=> ::overloaded_literals::FromLiteralStr::<#res>::into_self());
-    res
 }
 
 impl Fold for Args {
     // We fold at the level of `Expr` because when we change a literal, the result will be an `Expr`.
-    fn fold_expr(&mut self, expr: syn::Expr) -> syn::Expr {
+    fn fold_expr(&mut self, mut expr: syn::Expr) -> syn::Expr {
         // Needed since we want to traverse bottom-up and leave all other nodes intact:
         // let expr = syn::fold::fold_expr(self, expr);
 
+        if let Some(attrs) = expr_attrs_mut(&mut expr) {
+            if take_no_overload(attrs) {
+                StripNoOverload.visit_expr_mut(&mut expr);
+                return expr;
+            }
+        }
+
         let span = expr.span();
         match expr {
             // Negative int literals are represented as Expr::Unary(UnOp::Neg, Expr::Lit(...))
@@ -126,7 +626,12 @@ impl Fold for Args {
                 op: op @ UnOp::Neg(_),
                 expr: boxed_expr,
             }) => match &*boxed_expr {
-                Expr::Lit(expr_lit) => wrap_signed(expr_lit, span).unwrap_or_else(|| {
+                Expr::Lit(expr_lit) if !self.overloads(&expr_lit.lit) => Expr::Unary(ExprUnary {
+                    attrs,
+                    op,
+                    expr: boxed_expr,
+                }),
+                Expr::Lit(expr_lit) => self.wrap_signed(expr_lit, span).unwrap_or_else(|| {
                     Expr::Unary(ExprUnary {
                         attrs,
                         op,
@@ -138,23 +643,315 @@ impl Fold for Args {
                     Expr::Unary(ExprUnary { attrs, op, expr })
                 }
             },
+            Expr::Lit(expr_lit) if !self.overloads(&expr_lit.lit) => Expr::Lit(expr_lit),
             Expr::Lit(expr_lit) => {
                 // Positive int or string literals are 'plain' Expr::Lit
-                wrap_unsigned_or_str(expr_lit, span)
+                self.wrap_unsigned_or_str(expr_lit, span)
+            }
+            // `FromLiteralArray::into_self` takes the elements at runtime, so it cannot be used in const contexts.
+            Expr::Array(expr_array) if self.arrays && !self.in_const => {
+                let len = expr_array.elems.len();
+                let array = syn::fold::fold_expr_array(self, expr_array);
+                let krate = self.krate(span);
+                let len = respan(len, span);
+                parse_quote_spanned!(span=> #krate::FromLiteralArray::<_, #len>::into_self(#array))
+            }
+            Expr::If(expr_if) if self.patterns && !self.in_const => {
+                match syn::fold::fold_expr(self, Expr::If(expr_if)) {
+                    Expr::If(expr_if) => self.overload_if_let(expr_if),
+                    other => other,
+                }
             }
             other => syn::fold::fold_expr(self, other),
         }
     }
+
+    fn fold_local(&mut self, mut local: Local) -> Local {
+        if take_no_overload(&mut local.attrs) {
+            StripNoOverload.visit_local_mut(&mut local);
+            return local;
+        }
+        syn::fold::fold_local(self, local)
+    }
+
+    fn fold_expr_macro(&mut self, mut expr_macro: ExprMacro) -> ExprMacro {
+        expr_macro.mac = self.fold_macro_args(expr_macro.mac);
+        expr_macro
+    }
+
+    fn fold_stmt_macro(&mut self, mut stmt_macro: StmtMacro) -> StmtMacro {
+        if take_no_overload(&mut stmt_macro.attrs) {
+            return stmt_macro;
+        }
+        stmt_macro.mac = self.fold_macro_args(stmt_macro.mac);
+        stmt_macro
+    }
+
+    fn fold_arm(&mut self, mut arm: Arm) -> Arm {
+        if take_no_overload(&mut arm.attrs) {
+            StripNoOverload.visit_arm_mut(&mut arm);
+            return arm;
+        }
+        let arm = syn::fold::fold_arm(self, arm);
+        // The guards introduced for literal patterns cannot be evaluated in const contexts.
+        if self.patterns && !self.in_const {
+            self.overload_arm(arm)
+        } else {
+            arm
+        }
+    }
+
+    fn fold_item(&mut self, mut item: Item) -> Item {
+        if let Some(attrs) = item_attrs_mut(&mut item) {
+            if take_no_overload(attrs) {
+                StripNoOverload.visit_item_mut(&mut item);
+                return item;
+            }
+        }
+        // Nested items which are annotated themselves will be expanded separately,
+        // (possibly with different arguments) so we must not rewrite them twice.
+        if is_annotated(&item) {
+            return item;
+        }
+        syn::fold::fold_item(self, item)
+    }
+
+    // The bodies of `const fn`s are const contexts, the bodies of all other functions are not,
+    // even when they are nested inside a const context.
+    fn fold_item_fn(&mut self, item_fn: ItemFn) -> ItemFn {
+        let is_const = item_fn.sig.constness.is_some();
+        self.with_const_context(is_const, |args| syn::fold::fold_item_fn(args, item_fn))
+    }
+
+    fn fold_impl_item_fn(&mut self, mut impl_item_fn: ImplItemFn) -> ImplItemFn {
+        if take_no_overload(&mut impl_item_fn.attrs) {
+            StripNoOverload.visit_impl_item_fn_mut(&mut impl_item_fn);
+            return impl_item_fn;
+        }
+        let is_const = impl_item_fn.sig.constness.is_some();
+        self.with_const_context(is_const, |args| {
+            syn::fold::fold_impl_item_fn(args, impl_item_fn)
+        })
+    }
+
+    fn fold_trait_item_fn(&mut self, mut trait_item_fn: TraitItemFn) -> TraitItemFn {
+        if take_no_overload(&mut trait_item_fn.attrs) {
+            StripNoOverload.visit_trait_item_fn_mut(&mut trait_item_fn);
+            return trait_item_fn;
+        }
+        let is_const = trait_item_fn.sig.constness.is_some();
+        self.with_const_context(is_const, |args| {
+            syn::fold::fold_trait_item_fn(args, trait_item_fn)
+        })
+    }
+
+    // Inline const blocks like `const { 42 }`, as well as the initializers of (associated) consts and statics
+    // are evaluated at compile time, so literals inside them use the `ConstFromLiteral*` traits instead.
+    fn fold_expr_const(&mut self, expr_const: ExprConst) -> ExprConst {
+        self.with_const_context(true, |args| syn::fold::fold_expr_const(args, expr_const))
+    }
+
+    fn fold_item_const(&mut self, item_const: ItemConst) -> ItemConst {
+        self.with_const_context(true, |args| syn::fold::fold_item_const(args, item_const))
+    }
+
+    fn fold_item_static(&mut self, item_static: ItemStatic) -> ItemStatic {
+        self.with_const_context(true, |args| syn::fold::fold_item_static(args, item_static))
+    }
+
+    fn fold_impl_item_const(&mut self, mut impl_item_const: ImplItemConst) -> ImplItemConst {
+        if take_no_overload(&mut impl_item_const.attrs) {
+            StripNoOverload.visit_impl_item_const_mut(&mut impl_item_const);
+            return impl_item_const;
+        }
+        self.with_const_context(true, |args| {
+            syn::fold::fold_impl_item_const(args, impl_item_const)
+        })
+    }
+
+    fn fold_trait_item_const(&mut self, mut trait_item_const: TraitItemConst) -> TraitItemConst {
+        if take_no_overload(&mut trait_item_const.attrs) {
+            StripNoOverload.visit_trait_item_const_mut(&mut trait_item_const);
+            return trait_item_const;
+        }
+        self.with_const_context(true, |args| {
+            syn::fold::fold_trait_item_const(args, trait_item_const)
+        })
+    }
+
+    // The following positions require a const primitive value (or are not expressions at all),
+    // so literals inside them are left alone.
+
+    // Array lengths, const generic arguments inside types, etc.
+    fn fold_type(&mut self, ty: Type) -> Type {
+        ty
+    }
+
+    // Const generic arguments, like in `foo::<3>()`
+    fn fold_generic_argument(&mut self, generic_argument: GenericArgument) -> GenericArgument {
+        generic_argument
+    }
+
+    // Defaults of const generic parameters, like in `struct Foo<const N: usize = 3>`
+    fn fold_const_param(&mut self, const_param: ConstParam) -> ConstParam {
+        const_param
+    }
+
+    // `#[doc = "..."]`, `#[cfg(feature = "...")]`, etc.
+    fn fold_attribute(&mut self, attribute: Attribute) -> Attribute {
+        attribute
+    }
+
+    // Enum discriminants, like in `enum Foo { Bar = 1 }`
+    fn fold_variant(&mut self, variant: Variant) -> Variant {
+        variant
+    }
+
+    // Patterns, like in `match x { 0..=9 => ... }`
+    fn fold_pat(&mut self, pat: Pat) -> Pat {
+        pat
+    }
+
+    // The length of array repeat expressions like `[0; 16]`
+    fn fold_expr_repeat(&mut self, mut expr_repeat: ExprRepeat) -> ExprRepeat {
+        expr_repeat.expr = Box::new(self.fold_expr(*expr_repeat.expr));
+        expr_repeat
+    }
+}
+
+fn overload_item(args: &mut Args, item: Item) -> syn::Result<Item> {
+    match item {
+        Item::Fn(item_fn) => Ok(Item::Fn(args.fold_item_fn(item_fn))),
+        Item::Impl(item_impl) => Ok(Item::Impl(args.fold_item_impl(item_impl))),
+        Item::Trait(item_trait) => Ok(Item::Trait(args.fold_item_trait(item_trait))),
+        Item::Mod(item_mod) if item_mod.content.is_none() => Err(syn::Error::new_spanned(
+            item_mod,
+            "`#[overloaded_literals]` can only be used on inline modules (`mod name { ... }`)",
+        )),
+        Item::Mod(item_mod) => Ok(Item::Mod(args.fold_item_mod(item_mod))),
+        other => Err(syn::Error::new_spanned(
+            other,
+            "`#[overloaded_literals]` can only be used on functions, impl blocks, traits and modules",
+        )),
+    }
 }
 
 #[proc_macro_attribute]
-pub fn overloaded_literals(_metadata: TokenStream, input: TokenStream) -> TokenStream {
-    let input_fn = parse_macro_input!(input as ItemFn);
-    let mut args = Args;
-    let output = args.fold_item_fn(input_fn);
+pub fn overloaded_literals(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let mut args = Args::none();
+    let args_parser = syn::meta::parser(|meta| args.parse_meta(meta));
+    parse_macro_input!(metadata with args_parser);
+    let mut args = args.or_default();
+
+    let input_item = parse_macro_input!(input as Item);
+    match overload_item(&mut args, input_item) {
+        Ok(output) => TokenStream::from(quote!(#output)),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// The input of `ol!`: an expression, optionally preceded by `crate = path;` and/or `const;`.
+struct OlInput {
+    krate: Option<Path>,
+    in_const: bool,
+    expr: Expr,
+}
+
+/// Parses the optional `crate = path;` argument of the function-like macros.
+fn parse_crate_arg(input: ParseStream) -> syn::Result<Option<Path>> {
+    // NOTE: Checking for the `=` is needed to not mistake an expression like `crate::FOO` for the argument.
+    if input.peek(Token![crate]) && input.peek2(Token![=]) {
+        input.parse::<Token![crate]>()?;
+        input.parse::<Token![=]>()?;
+        let krate = input.parse()?;
+        input.parse::<Token![;]>()?;
+        Ok(Some(krate))
+    } else {
+        Ok(None)
+    }
+}
+
+impl Parse for OlInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let krate = parse_crate_arg(input)?;
+        // NOTE: Checking for the `;` is needed to not mistake an inline `const { ... }` block for the argument.
+        let in_const = input.peek(Token![const]) && input.peek2(Token![;]);
+        if in_const {
+            input.parse::<Token![const]>()?;
+            input.parse::<Token![;]>()?;
+        }
+        let expr = input.parse()?;
+        Ok(OlInput {
+            krate,
+            in_const,
+            expr,
+        })
+    }
+}
+
+#[proc_macro]
+pub fn ol(input: TokenStream) -> TokenStream {
+    let OlInput {
+        krate,
+        in_const,
+        expr,
+    } = parse_macro_input!(input as OlInput);
+    let mut args = Args {
+        krate,
+        in_const,
+        ..Args::default()
+    };
+    let output = args.fold_expr(expr);
     TokenStream::from(quote!(#output))
 }
 
+/// The input of `typestr!`: a string, byte string or C string literal, optionally preceded by `crate = path;`.
+struct TypeStrInput {
+    krate: Option<Path>,
+    lit: Lit,
+}
+
+impl Parse for TypeStrInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let krate = parse_crate_arg(input)?;
+        let lit = input.parse()?;
+        Ok(TypeStrInput { krate, lit })
+    }
+}
+
+impl TypeStrInput {
+    /// Builds the same type-level byte string as the `overloaded_literals` macro does for this literal.
+    fn build_type(self) -> syn::Result<TokenStream2> {
+        let bytes = match &self.lit {
+            lit if !lit.suffix().is_empty() => None,
+            Lit::Str(lit_str) => Some(lit_str.value().into_bytes()),
+            Lit::ByteStr(lit_byte_str) => Some(lit_byte_str.value()),
+            Lit::CStr(lit_c_str) => Some(lit_c_str.value().into_bytes_with_nul()),
+            _ => None,
+        };
+        let Some(bytes) = bytes else {
+            return Err(syn::Error::new(
+                self.lit.span(),
+                "`typestr!` expects a string, byte string or C string literal without a suffix",
+            ));
+        };
+        let args = Args {
+            krate: self.krate,
+            ..Args::default()
+        };
+        Ok(args.build_type_bytes(&bytes, self.lit.span()))
+    }
+}
+
+#[proc_macro]
+pub fn typestr(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as TypeStrInput);
+    match input.build_type() {
+        Ok(output) => output.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
 // These tests are mainly here for debugging;
 // They (only) ensure the happy path does not crash.
 // (And if it does, we have relatively easy debugging)
@@ -162,7 +959,8 @@ pub fn overloaded_literals(_metadata: TokenStream, input: TokenStream) -> TokenS
 // More proper full-range tests can be found in the main crate.
 #[cfg(test)]
 mod tests {
-    use syn::parse_quote;
+    use quote::ToTokens;
+    use syn::{parse::Parser, parse_quote, ItemImpl, ItemMod, ItemTrait};
 
     use super::*;
 
@@ -174,7 +972,7 @@ mod tests {
                 res
             }
         };
-        let mut args = Args;
+        let mut args = Args::default();
         let _out = args.fold_item_fn(input_fun);
         // println!("{:?}", out)
     }
@@ -187,7 +985,7 @@ mod tests {
                 res
             }
         };
-        let mut args = Args;
+        let mut args = Args::default();
         let _out = args.fold_item_fn(input_fun);
         // println!("{:?}", out)
     }
@@ -200,11 +998,196 @@ mod tests {
                 res
             }
         };
-        let mut args = Args;
+        let mut args = Args::default();
         let _out = args.fold_item_fn(input_fun);
         // println!("{:?}", out)
     }
 
+    #[test]
+    fn char_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let digit: HexDigit = 'f';
+                let delimiter: Delimiter = b',';
+            }
+        };
+        let out = fold_to_string(input_fun.clone());
+        assert!(
+            out.contains(":: overloaded_literals :: FromLiteralChar :: < 'f' > :: into_self ()")
+        );
+        assert!(
+            out.contains(":: overloaded_literals :: FromLiteralByte :: < b',' > :: into_self ()")
+        );
+
+        let mut args = parse_args(quote!(char)).unwrap();
+        let out = args.fold_item_fn(input_fun).to_token_stream().to_string();
+        assert!(out.contains("FromLiteralChar"));
+        assert!(out.contains("b','") && !out.contains("FromLiteralByte"));
+    }
+
+    #[test]
+    fn byte_str_example() {
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                let magic: Magic = b"\x89P";
+                const HEADER: &[u8] = b"GET";
+            }
+        });
+        assert!(out.contains("FromLiteralByteStr :: < :: overloaded_literals :: type_bytes :: Chunk < 2usize , 20617u128 >"));
+        assert!(out.contains("__private :: const_byte_str :: <"));
+    }
+
+    #[test]
+    fn c_str_example() {
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                let symbol: SymbolName = c"a";
+            }
+        });
+        assert!(out.contains("FromLiteralCStr :: < :: overloaded_literals :: type_bytes :: Chunk < 2usize , 97u128 >"));
+    }
+
+    #[test]
+    fn long_str_example() {
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                let text: Text = "0123456789abcdef0123456789abcdef01234567";
+            }
+        });
+        assert!(out.contains("type_bytes :: Concat < 40usize , :: overloaded_literals :: type_bytes :: Chunk < 16usize ,"));
+        assert!(out.contains("type_bytes :: Concat < 24usize , :: overloaded_literals :: type_bytes :: Chunk < 16usize ,"));
+        assert!(out.contains("type_bytes :: Chunk < 8usize ,"));
+    }
+
+    #[test]
+    fn suffix_example() {
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                let timeout: Duration = 30ms;
+                let back: Duration = -2s;
+                let index = 10usize;
+                let ratio = 2.5f32;
+            }
+        });
+        assert!(out.contains("FromLiteralSuffixedUnsigned :: < :: overloaded_literals :: type_bytes :: Chunk < 2usize , 29549u128 > , 30 >"));
+        assert!(out.contains("FromLiteralSuffixedSigned :: < :: overloaded_literals :: type_bytes :: Chunk < 1usize , 115u128 > , - 2 >"));
+        assert!(out.contains("let index = 10usize"));
+        assert!(out.contains("let ratio = 2.5f32"));
+
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                let bytes = b"x"foo;
+                let letter = 'x'foo;
+            }
+        });
+        assert!(out.contains("let bytes = :: core :: compile_error ! { \"the custom suffix `foo` is not supported on byte string literals"));
+        assert!(out.contains("let letter = :: core :: compile_error ! { \"the custom suffix `foo` is not supported on char literals"));
+    }
+
+    #[test]
+    fn refused_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let index = 10usize;
+                let neg = -1.5f32;
+                let plain = #[no_overload] 10usize;
+            }
+        };
+        let out = fold_to_string(input_fun.clone());
+        assert!(out.contains("let index = 10usize"));
+        assert!(out.contains("let neg = - 1.5f32"));
+
+        let mut args = parse_args(quote!(strict)).unwrap();
+        let out = args.fold_item_fn(input_fun).into_token_stream().to_string();
+        assert!(out.contains("let index = :: core :: compile_error ! { \"literals with the built-in suffix `usize` are not overloaded (denied by `strict`)"));
+        assert!(out.contains("let neg = :: core :: compile_error ! { \"literals with the built-in suffix `f32` are not overloaded (denied by `strict`)"));
+        assert!(out.contains("let plain = 10usize"));
+
+        // Literals the macro does not look at are not affected by `strict`
+        let mut args = parse_args(quote!(int, strict)).unwrap();
+        let out = args
+            .fold_item_fn(parse_quote! {
+                fn foo() {
+                    let name = "x";
+                    let zeroes = [zero; 4usize];
+                }
+            })
+            .into_token_stream()
+            .to_string();
+        assert!(out.contains("let name = \"x\""));
+        assert!(out.contains("[zero ; 4usize]"));
+    }
+
+    #[test]
+    fn radix_example() {
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                let mask: Bits8 = 0b0000_1111;
+                let word: Word = 0x00FF;
+                let perms: u16 = 0o755;
+                let dec: u8 = 255;
+                let typed = 0xFFu8;
+            }
+        });
+        assert!(out.contains(":: overloaded_literals :: FromLiteralRadix :: < 0b0000_1111 , 2u32 , 8u32 > :: into_self ()"));
+        assert!(out.contains("FromLiteralRadix :: < 0x00FF , 16u32 , 4u32 >"));
+        assert!(out.contains("FromLiteralRadix :: < 0o755 , 8u32 , 3u32 >"));
+        assert!(out.contains("FromLiteralUnsigned :: < 255 >"));
+        assert!(out.contains("let typed = 0xFFu8"));
+    }
+
+    #[test]
+    fn big_int_example() {
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                let big: U256 = 340282366920938463463374607431768211456;
+                let neg: I256 = -170141183460469231731687303715884105729;
+                let min: i128 = -170141183460469231731687303715884105728;
+            }
+        });
+        assert!(out.contains(":: overloaded_literals :: FromLiteralBigInt :: < :: overloaded_literals :: type_bigint :: BigInt < false , :: overloaded_literals :: type_bigint :: Concat < 3usize , :: overloaded_literals :: type_bigint :: Limb < 0u64 > , :: overloaded_literals :: type_bigint :: Concat < 2usize , :: overloaded_literals :: type_bigint :: Limb < 0u64 > , :: overloaded_literals :: type_bigint :: Limb < 1u64 > > > >"));
+        assert!(out.contains("BigInt < true , :: overloaded_literals :: type_bigint :: Concat < 2usize , :: overloaded_literals :: type_bigint :: Limb < 1u64 > , :: overloaded_literals :: type_bigint :: Limb < 9223372036854775808u64 > > >"));
+        assert!(out.contains("FromLiteralSigned :: < - 170141183460469231731687303715884105728 >"));
+
+        let huge = LitInt::new(&format!("1{}", "0".repeat(1300)), Span::call_site());
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                let huge: BigUint = #huge;
+            }
+        });
+        assert!(out.contains(
+            "BigInt < false , :: overloaded_literals :: type_bigint :: Concat < 68usize ,"
+        ));
+
+        assert_eq!(bigint_limbs("0"), vec![]);
+        assert_eq!(bigint_limbs("18446744073709551615"), vec![u64::MAX]);
+        assert_eq!(bigint_limbs("18446744073709551616"), vec![0, 1]);
+    }
+
+    #[test]
+    fn decimal_example() {
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                let price: Cents = 19.99;
+                let discount: Cents = -0.1;
+            }
+        });
+        let bits = 19.99f64.to_bits();
+        assert!(out.contains(&format!(":: overloaded_literals :: FromLiteralFloat :: < :: overloaded_literals :: type_decimal :: Decimal < {}u64 , 1999i128 , - 2i32 > >", bits)));
+        let bits = (-0.1f64).to_bits();
+        assert!(out.contains(&format!("Decimal < {}u64 , - 1i128 , - 1i32 >", bits)));
+
+        assert_eq!(decimal_parts("1.5e3"), Some((15, 2)));
+        assert_eq!(decimal_parts("1E-7"), Some((1, -7)));
+        assert_eq!(decimal_parts("0.10"), Some((10, -2)));
+        assert_eq!(decimal_parts("2."), Some((2, 0)));
+        assert_eq!(decimal_parts("1e+3"), Some((1, 3)));
+        assert_eq!(
+            decimal_parts("1234567890123456789012345678901234567890.0"),
+            None
+        );
+    }
+
     // #[test]
     // fn float_example() {
     //     let input_fun = parse_quote! {
@@ -218,6 +1201,420 @@ mod tests {
     //     // println!("{:?}", out)
     // }
 
+    #[test]
+    fn impl_example() {
+        let input_impl = parse_quote! {
+            impl Foo {
+                const BAR: u8 = 42;
+
+                fn new() -> Self {
+                    Foo(1, -2, "three")
+                }
+
+                fn is_true(&self) -> bool {
+                    true
+                }
+            }
+        };
+        let mut args = Args::default();
+        let _out = args.fold_item_impl(input_impl);
+        // println!("{:?}", out)
+    }
+
+    #[test]
+    fn trait_example() {
+        let input_trait = parse_quote! {
+            trait Foo {
+                const BAR: u8 = 42;
+
+                fn required(&self) -> u8;
+
+                fn provided(&self) -> u8 {
+                    self.required() + 10
+                }
+            }
+        };
+        let mut args = Args::default();
+        let _out = args.fold_item_trait(input_trait);
+        // println!("{:?}", out)
+    }
+
+    #[test]
+    fn mod_example() {
+        let input_mod = parse_quote! {
+            mod config {
+                const UNTOUCHED: u8 = 1;
+
+                fn retries() -> u8 {
+                    3
+                }
+
+                impl Foo {
+                    fn name() -> Self {
+                        Foo("foo")
+                    }
+                }
+
+                mod nested {
+                    fn timeout() -> u8 {
+                        -1
+                    }
+
+                    #[overloaded_literals]
+                    fn expanded_separately() -> u8 {
+                        4
+                    }
+                }
+            }
+        };
+        let mut args = Args::default();
+        let _out = args.fold_item_mod(input_mod);
+        // println!("{:?}", out)
+    }
+
+    #[test]
+    fn expr_example() {
+        let input_expr = parse_quote! {
+            {
+                let nonzero: NonZeroU32 = 42;
+                (nonzero, -1, "cstr\0", |x| x + 1)
+            }
+        };
+        let mut args = Args::default();
+        let _out = args.fold_expr(input_expr);
+        // println!("{:?}", out)
+    }
+
+    fn parse_args(tokens: impl ToTokens) -> syn::Result<Args> {
+        let mut args = Args::none();
+        let args_parser = syn::meta::parser(|meta| args.parse_meta(meta));
+        args_parser.parse2(tokens.into_token_stream())?;
+        Ok(args.or_default())
+    }
+
+    #[test]
+    fn args_example() {
+        let args = parse_args(quote!()).unwrap();
+        assert!(args.int && args.float && args.bool && args.str && args.char);
+
+        let args = parse_args(quote!(int, str)).unwrap();
+        assert!(args.int && !args.float && !args.bool && args.str && !args.char);
+
+        assert!(parse_args(quote!(int, nonsense)).is_err());
+        assert!(parse_args(quote!(int, int)).is_err());
+    }
+
+    #[test]
+    fn int_only_example() {
+        let input_fun = parse_quote! {
+            fn foo() {
+                let overloaded: NonZeroU8 = 1;
+                let also_overloaded: NonZeroI8 = -1;
+                let plain: f32 = 1.0;
+                let plain_too: &str = "bar";
+            }
+        };
+        let mut args = parse_args(quote!(int)).unwrap();
+        let _out = args.fold_item_fn(input_fun);
+        // println!("{:?}", out)
+    }
+
+    #[test]
+    fn no_overload_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let overloaded: NonZeroU8 = 1;
+                #[no_overload]
+                let counter: usize = 0;
+                let sum = overloaded.get() + #[no_overload] 2;
+                #[no_overload]
+                {
+                    let plain = 3;
+                }
+                #[no_overload]
+                fn nested() -> u8 {
+                    4
+                }
+            }
+        };
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        let out = out.to_token_stream().to_string();
+        assert!(!out.contains("no_overload"));
+        assert_eq!(out.matches("into_self").count(), 1);
+    }
+
+    #[test]
+    fn nested_no_overload_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                #[no_overload]
+                let v: u8 = {
+                    #[no_overload]
+                    let w: u8 = 5;
+                    w
+                };
+                #[no_overload]
+                match v {
+                    #[no_overload]
+                    0 => #[no_overload] 1,
+                    _ => 2,
+                };
+            }
+        };
+        let out = fold_to_string(input_fun);
+        assert!(!out.contains("no_overload"));
+        assert!(!out.contains("into_self"));
+    }
+
+    #[test]
+    fn no_overload_const_example() {
+        let input_impl: ItemImpl = parse_quote! {
+            impl S {
+                #[no_overload]
+                const K: u8 = 1;
+                const L: NonZeroU8 = 2;
+            }
+        };
+        let out = Args::default().fold_item_impl(input_impl);
+        let out = out.to_token_stream().to_string();
+        assert!(!out.contains("no_overload"));
+        assert!(out.contains("const K : u8 = 1 ;"));
+        assert!(out.contains("const_unsigned :: < 2 , _ >"));
+
+        let input_trait: ItemTrait = parse_quote! {
+            trait T {
+                #[no_overload]
+                const K: u8 = 1;
+            }
+        };
+        let out = Args::default().fold_item_trait(input_trait);
+        let out = out.to_token_stream().to_string();
+        assert!(!out.contains("no_overload"));
+        assert!(out.contains("const K : u8 = 1 ;"));
+    }
+
+    fn fold_to_string(input_fun: ItemFn) -> String {
+        let mut args = Args::default();
+        let out = args.fold_item_fn(input_fun);
+        out.to_token_stream().to_string()
+    }
+
+    #[test]
+    fn macro_example() {
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                let numbers: Vec<NonZeroU8> = vec![1, 2, 3];
+                let zeroes: Vec<NonZeroU8> = vec![4; 5];
+                assert_eq!(numbers.first(), 6, "first number was {:?} instead of {}", numbers, 7);
+                println!("{}", 8);
+                assert!(matches!(numbers.first(), Some(9)));
+                my_macro!("tenth", 11);
+                my_other_macro!(x => 12);
+                let s = stringify!(13);
+            }
+        });
+        for rewritten in ["1", "2", "3", "4", "5", "6", "11"] {
+            let expected = format!("FromLiteralUnsigned :: < {rewritten} >");
+            assert!(out.contains(&expected), "{rewritten} was not rewritten");
+        }
+        assert!(out.contains("\"first number was {:?} instead of {}\""));
+        for plain in ["7", "8", "9", "12", "13"] {
+            let unexpected = format!("FromLiteralUnsigned :: < {plain} >");
+            assert!(!out.contains(&unexpected), "{plain} was rewritten");
+        }
+        assert!(out.contains("my_macro ! (\"tenth\""));
+    }
+
+    #[test]
+    fn macro_format_args_example() {
+        let out = fold_to_string(parse_quote! {
+            fn foo() {
+                dbg!(1);
+                dbg!(-2, x + 3);
+                println!("{y} {x}", x = 4, y = -5);
+                println!("{x}", x = y + 6);
+            }
+        });
+        for plain in ["1", "2", "4", "5"] {
+            let unexpected = format!("< {plain} >");
+            assert!(!out.contains(&unexpected), "{plain} was rewritten");
+        }
+        assert!(out.contains("x = 4"));
+        for rewritten in ["3", "6"] {
+            let expected = format!("FromLiteralUnsigned :: < {rewritten} >");
+            assert!(out.contains(&expected), "{rewritten} was not rewritten");
+        }
+    }
+
+    #[test]
+    fn const_position_example() {
+        let out = fold_to_string(parse_quote! {
+            #[doc = "1"]
+            fn foo(bytes: [u8; 2]) -> [u8; 3] {
+                let zeroes: [NonZeroU8; 4] = [5; 6];
+                let sized = size_of::<[u8; 7]>();
+                let generic = bar::<8>();
+                let method = bytes.baz::<9>();
+                enum Foo {
+                    Bar = 11,
+                }
+                struct Baz<const N: usize = 12>;
+                match sized {
+                    13 => {}
+                    14..=15 => {}
+                    _ => {}
+                }
+                #[cfg(feature = "16")]
+                let conditional = 17;
+                asm!("nop", const 18);
+            }
+        });
+        assert!(out.contains("FromLiteralUnsigned :: < 5 >"));
+        assert!(out.contains("FromLiteralUnsigned :: < 17 >"));
+        assert_eq!(out.matches("into_self").count(), 2);
+    }
+
+    #[test]
+    fn crate_path_example() {
+        let mut args = parse_args(quote!(int, crate = facade::overloaded_literals)).unwrap();
+        assert!(args.int && !args.str);
+        assert!(parse_args(quote!(crate = a, crate = b)).is_err());
+
+        let out = args.fold_item_fn(parse_quote! {
+            fn foo() {
+                let x: NonZeroU8 = 1;
+                let y: &str = "hello";
+            }
+        });
+        let out = out.to_token_stream().to_string();
+        assert!(out.contains("facade :: overloaded_literals :: FromLiteralUnsigned :: < 1 >"));
+        assert!(!out.contains(":: overloaded_literals :: FromLiteralStr"));
+
+        let input: OlInput = parse_quote!(crate = crate::reexports; "hi");
+        assert!(input.krate.is_some());
+        let input: OlInput = parse_quote!(crate::FOO);
+        assert!(input.krate.is_none());
+        assert!(!input.in_const);
+        let input: OlInput = parse_quote!(crate = crate::reexports; const; 5);
+        assert!(input.krate.is_some());
+        assert!(input.in_const);
+        let input: OlInput = parse_quote!(const { 5 });
+        assert!(!input.in_const);
+    }
+
+    #[test]
+    fn typestr_example() {
+        let input: TypeStrInput = parse_quote!("GET");
+        let out = input.build_type().unwrap().to_string();
+        assert_eq!(
+            out,
+            ":: overloaded_literals :: type_bytes :: Chunk < 3usize , 5522759u128 >"
+        );
+        let input: TypeStrInput = parse_quote!(crate = crate::reexports; c"a");
+        let out = input.build_type().unwrap().to_string();
+        assert_eq!(
+            out,
+            "crate :: reexports :: type_bytes :: Chunk < 2usize , 97u128 >"
+        );
+        let input: TypeStrInput = parse_quote!(42);
+        assert!(input.build_type().is_err());
+        let input: TypeStrInput = parse_quote!("ms"_suffix);
+        assert!(input.build_type().is_err());
+    }
+
+    #[test]
+    fn const_context_example() {
+        let item_mod: ItemMod = parse_quote! {
+            mod foo {
+                const ONE: NonZeroU8 = 1;
+                static TWO: &str = "two";
+                const fn three() -> f32 {
+                    -3.0
+                }
+                fn four() -> NonZeroU8 {
+                    const { 4 }
+                }
+                impl Bar {
+                    const FIVE: i8 = -5;
+                    fn six() -> NonZeroU8 {
+                        6
+                    }
+                }
+            }
+        };
+        let out = Args::default().fold_item_mod(item_mod);
+        let out = out.to_token_stream().to_string();
+        assert!(out.contains("const_unsigned :: < 1 , _ >"));
+        assert!(out.contains("const_str :: < :: overloaded_literals :: type_bytes :: Chunk"));
+        assert!(out.contains("const_float :: < :: overloaded_literals :: type_decimal :: Decimal"));
+        assert!(out.contains("const_unsigned :: < 4 , _ >"));
+        assert!(out.contains("const_signed :: < - 5 , _ >"));
+        assert!(out.contains("FromLiteralUnsigned :: < 6 > :: into_self ()"));
+        assert_eq!(out.matches("into_self").count(), 1);
+    }
+
+    #[test]
+    fn patterns_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo(x: Option<NonZeroU8>) {
+                match x {
+                    Some(1) => {}
+                    Some(2..=9) if true => {}
+                    _ => {}
+                }
+                if let Some(-3) = x {}
+            }
+        };
+        let out = Args::default().fold_item_fn(input_fun.clone());
+        assert!(out.to_token_stream().to_string().contains("Some (1)"));
+
+        let mut args = parse_args(quote!(int, patterns)).unwrap();
+        let out = args.fold_item_fn(input_fun).to_token_stream().to_string();
+        assert!(!out.contains("Some (1)"));
+        assert!(out.contains("pattern_eq (__overloaded_literal_0 , :: overloaded_literals :: FromLiteralUnsigned :: < 1 > :: into_self ())"));
+        assert!(out.contains("pattern_ge (__overloaded_literal_0 , :: overloaded_literals :: FromLiteralUnsigned :: < 2 > :: into_self ())"));
+        assert!(out.contains("pattern_le (__overloaded_literal_0 , :: overloaded_literals :: FromLiteralUnsigned :: < 9 > :: into_self ())) && (true)"));
+        assert!(out.contains("FromLiteralSigned :: < - 3 >"));
+        assert!(!out.contains("if let"));
+    }
+
+    #[test]
+    fn patterns_reference_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo(x: &Option<NonZeroU8>) {
+                match x {
+                    Some(1) => {}
+                    &Some(2) => {}
+                    _ => {}
+                }
+            }
+        };
+        let mut args = parse_args(quote!(int, patterns)).unwrap();
+        let out = args.fold_item_fn(input_fun).to_token_stream().to_string();
+        assert!(out.contains("Some (ref __overloaded_literal_0)"));
+        assert!(out.contains("& Some (ref __overloaded_literal_0)"));
+    }
+
+    #[test]
+    fn arrays_example() {
+        let input_fun: ItemFn = parse_quote! {
+            fn foo() {
+                let list: Bounded<4> = [1, 2, 3];
+                const ARR: [u8; 2] = [4, 5];
+            }
+        };
+        let out = fold_to_string(input_fun.clone());
+        assert!(out.contains(
+            "let list : Bounded < 4 > = [:: overloaded_literals :: FromLiteralUnsigned :: < 1 >"
+        ));
+
+        let mut args = parse_args(quote!(int, arrays)).unwrap();
+        let out = args.fold_item_fn(input_fun).into_token_stream().to_string();
+        assert!(out.contains(":: overloaded_literals :: FromLiteralArray :: < _ , 3usize > :: into_self ([:: overloaded_literals :: FromLiteralUnsigned :: < 1 > :: into_self ()"));
+        assert!(out.contains("const ARR : [u8 ; 2] = [:: overloaded_literals :: __private :: const_unsigned :: < 4 , _ > ()"));
+    }
+
     #[test]
     fn mixed_example() {
         let input_fun = parse_quote! {
@@ -228,7 +1625,7 @@ mod tests {
                 let four : i8 = -33;
             }
         };
-        let mut args = Args;
+        let mut args = Args::default();
         let _out = args.fold_item_fn(input_fun);
         // println!("{:?}", out)
     }
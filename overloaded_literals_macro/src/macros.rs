@@ -0,0 +1,168 @@
+//! Rewriting of literals inside the arguments of macro invocations like `vec![1, 2, 3]` or `assert_eq!(x, 6)`.
+//!
+//! Macro arguments are only a token stream, so we try to parse them as comma-separated expressions.
+//! If this fails, the tokens are left alone.
+use crate::Args;
+use quote::quote;
+use syn::{
+    fold::Fold, parse::ParseStream, punctuated::Punctuated, Expr, ExprAssign, ExprLit, ExprUnary,
+    Lit, Macro, Token, UnOp,
+};
+
+/// How the arguments of a particular macro should be treated.
+enum MacroKind {
+    /// Macros whose arguments must never be touched, because they require actual literal tokens,
+    /// or are not expressions at all.
+    Opaque,
+    /// Macros taking comma-separated expressions.
+    ///
+    /// If `format_string` is set, the argument at that position is the (optional) format string
+    /// and all arguments after it are formatting arguments.
+    /// If it is not set, all arguments are treated like formatting arguments (as for `dbg!`).
+    Exprs { format_string: Option<usize> },
+    /// `vec![a, b, c]` or `vec![elem; len]`
+    Vec,
+    /// Macros like `matches!(expr, pattern)` where only the first argument is an expression.
+    FirstExpr,
+    /// Any macro we do not know about.
+    Unknown,
+}
+
+fn macro_kind(name: &str) -> MacroKind {
+    match name {
+        "asm" | "global_asm" | "naked_asm" | "concat" | "concat_idents" | "stringify"
+        | "include" | "include_str" | "include_bytes" | "env" | "option_env" | "cfg" | "line"
+        | "column" | "file" | "module_path" | "compile_error" | "macro_rules" | "thread_local"
+        | "ol" => MacroKind::Opaque,
+        "format" | "print" | "println" | "eprint" | "eprintln" | "format_args" | "panic"
+        | "unreachable" | "todo" | "unimplemented" => MacroKind::Exprs {
+            format_string: Some(0),
+        },
+        "write" | "writeln" | "assert" | "debug_assert" => MacroKind::Exprs {
+            format_string: Some(1),
+        },
+        "assert_eq" | "assert_ne" | "debug_assert_eq" | "debug_assert_ne" => MacroKind::Exprs {
+            format_string: Some(2),
+        },
+        "dbg" => MacroKind::Exprs {
+            format_string: None,
+        },
+        "vec" => MacroKind::Vec,
+        "matches" | "assert_matches" | "debug_assert_matches" => MacroKind::FirstExpr,
+        _ => MacroKind::Unknown,
+    }
+}
+
+fn is_str_lit(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(_),
+            ..
+        })
+    )
+}
+
+fn is_bare_lit(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        // Named formatting arguments, like `x = 5` in `println!("{x}", x = 5)`
+        Expr::Assign(ExprAssign { right, .. }) => is_bare_lit(right),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => matches!(**expr, Expr::Lit(_)),
+        _ => false,
+    }
+}
+
+impl Args {
+    /// Rewrites the literals inside the arguments of `mac`,
+    /// leaving it unchanged if its arguments cannot be parsed as expressions.
+    pub(crate) fn fold_macro_args(&mut self, mac: Macro) -> Macro {
+        let name = match mac.path.segments.last() {
+            Some(segment) => segment.ident.to_string(),
+            None => return mac,
+        };
+        let tokens = match macro_kind(&name) {
+            MacroKind::Opaque => return mac,
+            MacroKind::Exprs { format_string } => {
+                let Ok(args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                else {
+                    return mac;
+                };
+                let args = self.fold_macro_exprs(args, |index, arg| match format_string {
+                    // The format string needs to stay a literal,
+                    // and literals passed directly as formatting arguments could never have their type inferred.
+                    Some(format_string) if index == format_string => is_str_lit(arg),
+                    Some(format_string) if index > format_string => is_bare_lit(arg),
+                    Some(_) => false,
+                    None => is_bare_lit(arg),
+                });
+                quote!(#args)
+            }
+            MacroKind::Unknown => {
+                let Ok(args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                else {
+                    return mac;
+                };
+                // String literals passed to unknown macros are most likely format strings
+                let args = self.fold_macro_exprs(args, |_index, arg| is_str_lit(arg));
+                quote!(#args)
+            }
+            MacroKind::Vec => {
+                if let Ok((elem, semi, len)) = mac.parse_body_with(|input: ParseStream| {
+                    Ok((
+                        input.parse::<Expr>()?,
+                        input.parse::<Token![;]>()?,
+                        input.parse::<Expr>()?,
+                    ))
+                }) {
+                    let elem = self.fold_expr(elem);
+                    let len = self.fold_expr(len);
+                    quote!(#elem #semi #len)
+                } else if let Ok(args) =
+                    mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                {
+                    let args = self.fold_macro_exprs(args, |_index, _arg| false);
+                    quote!(#args)
+                } else {
+                    return mac;
+                }
+            }
+            MacroKind::FirstExpr => {
+                let Ok((first, rest)) = mac.parse_body_with(|input: ParseStream| {
+                    Ok((
+                        input.parse::<Expr>()?,
+                        input.parse::<proc_macro2::TokenStream>()?,
+                    ))
+                }) else {
+                    return mac;
+                };
+                let first = self.fold_expr(first);
+                quote!(#first #rest)
+            }
+        };
+        Macro { tokens, ..mac }
+    }
+
+    fn fold_macro_exprs(
+        &mut self,
+        args: Punctuated<Expr, Token![,]>,
+        keep: impl Fn(usize, &Expr) -> bool,
+    ) -> Punctuated<Expr, Token![,]> {
+        args.into_pairs()
+            .enumerate()
+            .map(|(index, pair)| {
+                let (arg, comma) = pair.into_tuple();
+                let arg = if keep(index, &arg) {
+                    arg
+                } else {
+                    self.fold_expr(arg)
+                };
+                syn::punctuated::Pair::new(arg, comma)
+            })
+            .collect()
+    }
+}
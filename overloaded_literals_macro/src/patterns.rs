@@ -0,0 +1,269 @@
+//! Rewriting of literal patterns, like in `match x { 0 => ..., "GET" => ..., 1..=9 => ... }`.
+//!
+//! Patterns can only contain constants, so a literal pattern is replaced by a fresh binding,
+//! and the comparison against the overloaded literal is moved into the guard of the match arm:
+//!
+//! ```ignore
+//! match x {
+//!     Some(0) => ...,
+//! }
+//! // becomes:
+//! match x {
+//!     Some(ref __overloaded_literal_0) if pattern_eq(__overloaded_literal_0, FromLiteralUnsigned::<0>::into_self()) => ...,
+//! }
+//! ```
+use crate::Args;
+use proc_macro2::Span;
+use quote::format_ident;
+use syn::{
+    fold::Fold, parse_quote, spanned::Spanned, Arm, Expr, ExprIf, ExprLit, ExprRange, ExprUnary,
+    Ident, Lit, LitFloat, LitInt, Pat, RangeLimits, UnOp,
+};
+
+/// The bindings and guard conditions introduced while rewriting a single pattern.
+#[derive(Default)]
+struct PatRewrite {
+    bindings: usize,
+    conditions: Vec<Expr>,
+}
+
+impl PatRewrite {
+    /// The name of the next binding.
+    ///
+    /// NOTE: The binding is hygienic, so it can never clash with the user's own bindings.
+    fn next_binding(&self) -> Ident {
+        format_ident!(
+            "__overloaded_literal_{}",
+            self.bindings,
+            span = Span::mixed_site()
+        )
+    }
+
+    fn guard(self) -> Option<Expr> {
+        let mut conditions = self.conditions.into_iter();
+        let first = conditions.next()?;
+        Some(conditions.fold(first, |acc, condition| parse_quote!(#acc && #condition)))
+    }
+}
+
+/// Turns a literal pattern back into an expression,
+/// since syn represents negative literal patterns like `-1` as a single literal.
+fn lit_to_expr(expr_lit: &ExprLit) -> Expr {
+    let span = expr_lit.lit.span();
+    let negated = |lit: Lit| -> Expr {
+        Expr::Unary(ExprUnary {
+            attrs: Vec::new(),
+            op: UnOp::Neg(syn::Token![-](span)),
+            expr: Box::new(Expr::Lit(ExprLit {
+                attrs: Vec::new(),
+                lit,
+            })),
+        })
+    };
+    match &expr_lit.lit {
+        Lit::Int(lit_int) if lit_int.to_string().starts_with('-') => {
+            negated(Lit::Int(LitInt::new(&lit_int.to_string()[1..], span)))
+        }
+        Lit::Float(lit_float) if lit_float.to_string().starts_with('-') => {
+            negated(Lit::Float(LitFloat::new(&lit_float.to_string()[1..], span)))
+        }
+        _ => Expr::Lit(expr_lit.clone()),
+    }
+}
+
+fn is_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => matches!(**expr, Expr::Lit(_)),
+        _ => false,
+    }
+}
+
+impl Args {
+    /// Overloads a literal (pattern) bound, returning `None` if it is not overloaded.
+    fn overload_bound(&mut self, expr: &Expr) -> Option<Expr> {
+        let expr = match expr {
+            Expr::Lit(expr_lit) => lit_to_expr(expr_lit),
+            other => other.clone(),
+        };
+        if !is_literal(&expr) {
+            return None;
+        }
+        let overloaded = self.fold_expr(expr);
+        (!is_literal(&overloaded)).then_some(overloaded)
+    }
+
+    /// Builds the guard conditions that check whether `binding` matches the literal or range pattern `pat`.
+    ///
+    /// Returns `None` if `pat` is not a literal or range pattern, or if its literals are not overloaded.
+    fn pat_conditions(&mut self, pat: &Pat, binding: &Ident) -> Option<Expr> {
+        let krate = self.krate(pat.span());
+        match pat {
+            Pat::Lit(expr_lit) => {
+                let lit = self.overload_bound(&Expr::Lit(expr_lit.clone()))?;
+                Some(parse_quote!(#krate::__private::pattern_eq(#binding, #lit)))
+            }
+            Pat::Range(ExprRange {
+                start, limits, end, ..
+            }) => {
+                let start = match start {
+                    Some(start) => Some(self.overload_bound(start)?),
+                    None => None,
+                };
+                let end = match end {
+                    Some(end) => Some(self.overload_bound(end)?),
+                    None => None,
+                };
+                let start: Option<Expr> = start
+                    .map(|start| parse_quote!(#krate::__private::pattern_ge(#binding, #start)));
+                let end: Option<Expr> = end.map(|end| match limits {
+                    RangeLimits::HalfOpen(_) => {
+                        parse_quote!(#krate::__private::pattern_lt(#binding, #end))
+                    }
+                    RangeLimits::Closed(_) => {
+                        parse_quote!(#krate::__private::pattern_le(#binding, #end))
+                    }
+                });
+                match (start, end) {
+                    (Some(start), Some(end)) => Some(parse_quote!((#start && #end))),
+                    (Some(condition), None) | (None, Some(condition)) => Some(condition),
+                    (None, None) => None,
+                }
+            }
+            Pat::Paren(pat_paren) => self.pat_conditions(&pat_paren.pat, binding),
+            Pat::Or(pat_or) => {
+                let mut conditions = Vec::new();
+                for case in &pat_or.cases {
+                    conditions.push(self.pat_conditions(case, binding)?);
+                }
+                let mut conditions = conditions.into_iter();
+                let first = conditions.next()?;
+                Some(conditions.fold(first, |acc, condition| parse_quote!(#acc || #condition)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Replaces all overloaded literal (and range) sub-patterns of `pat` by fresh bindings,
+    /// recording the guard conditions that need to hold for the original pattern to match.
+    fn overload_pat(&mut self, pat: Pat, rewrite: &mut PatRewrite) -> Pat {
+        match pat {
+            Pat::Lit(_) | Pat::Range(_) | Pat::Or(_) | Pat::Paren(_) => {
+                let binding = rewrite.next_binding();
+                match self.pat_conditions(&pat, &binding) {
+                    Some(condition) => {
+                        rewrite.bindings += 1;
+                        rewrite.conditions.push(condition);
+                        // NOTE: Binding with `ref` borrows rather than moves the matched value,
+                        // so the guard always compares a `&T`, whether the scrutinee is matched by value
+                        // or through a reference (match ergonomics).
+                        // The 2024 edition rejects an explicit `ref` inside an implicitly-borrowing pattern
+                        // only when it is written in the source itself; tokens generated by this macro
+                        // come from a macro expansion, so they are accepted there too.
+                        parse_quote!(ref #binding)
+                    }
+                    None => {
+                        match pat {
+                            Pat::Paren(mut pat_paren) => {
+                                pat_paren.pat =
+                                    Box::new(self.overload_pat(*pat_paren.pat, rewrite));
+                                Pat::Paren(pat_paren)
+                            }
+                            // Different cases of an or-pattern must bind the same names,
+                            // so we cannot introduce bindings for only some of them.
+                            other => other,
+                        }
+                    }
+                }
+            }
+            Pat::Reference(mut pat_reference) => {
+                pat_reference.pat = Box::new(self.overload_pat(*pat_reference.pat, rewrite));
+                Pat::Reference(pat_reference)
+            }
+            Pat::Slice(mut pat_slice) => {
+                pat_slice.elems = pat_slice
+                    .elems
+                    .into_iter()
+                    .map(|elem| self.overload_pat(elem, rewrite))
+                    .collect();
+                Pat::Slice(pat_slice)
+            }
+            Pat::Tuple(mut pat_tuple) => {
+                pat_tuple.elems = pat_tuple
+                    .elems
+                    .into_iter()
+                    .map(|elem| self.overload_pat(elem, rewrite))
+                    .collect();
+                Pat::Tuple(pat_tuple)
+            }
+            Pat::TupleStruct(mut pat_tuple_struct) => {
+                pat_tuple_struct.elems = pat_tuple_struct
+                    .elems
+                    .into_iter()
+                    .map(|elem| self.overload_pat(elem, rewrite))
+                    .collect();
+                Pat::TupleStruct(pat_tuple_struct)
+            }
+            Pat::Struct(mut pat_struct) => {
+                pat_struct.fields = pat_struct
+                    .fields
+                    .into_iter()
+                    .map(|mut field_pat| {
+                        field_pat.pat = Box::new(self.overload_pat(*field_pat.pat, rewrite));
+                        field_pat
+                    })
+                    .collect();
+                Pat::Struct(pat_struct)
+            }
+            other => other,
+        }
+    }
+
+    pub(crate) fn overload_arm(&mut self, mut arm: Arm) -> Arm {
+        let mut rewrite = PatRewrite::default();
+        arm.pat = self.overload_pat(arm.pat, &mut rewrite);
+        if let Some(condition) = rewrite.guard() {
+            let span = arm.pat.span();
+            let guard = match arm.guard {
+                Some((_, guard)) => parse_quote!(#condition && (#guard)),
+                None => condition,
+            };
+            arm.guard = Some((syn::Token![if](span), Box::new(guard)));
+        }
+        arm
+    }
+
+    /// Rewrites `if let PAT = EXPR { ... } else { ... }` to the equivalent `match`
+    /// if `PAT` contains literal patterns, since `if let` does not support guards.
+    pub(crate) fn overload_if_let(&mut self, expr_if: ExprIf) -> Expr {
+        let Expr::Let(expr_let) = *expr_if.cond else {
+            return Expr::If(expr_if);
+        };
+        let mut rewrite = PatRewrite::default();
+        let pat = self.overload_pat((*expr_let.pat).clone(), &mut rewrite);
+        let Some(condition) = rewrite.guard() else {
+            return Expr::If(ExprIf {
+                cond: Box::new(Expr::Let(expr_let)),
+                ..expr_if
+            });
+        };
+        let attrs = expr_if.attrs;
+        let scrutinee = expr_let.expr;
+        let then_branch = expr_if.then_branch;
+        let else_branch = match expr_if.else_branch {
+            Some((_, else_branch)) => *else_branch,
+            None => parse_quote!({}),
+        };
+        parse_quote! {
+            #(#attrs)*
+            match #scrutinee {
+                #pat if #condition => #then_branch,
+                _ => #else_branch,
+            }
+        }
+    }
+}